@@ -0,0 +1,207 @@
+use core::time::Duration;
+use std::time::Instant;
+
+use bevy_app::App;
+use bevy_asset::Assets;
+use bevy_camera::RenderTarget;
+use bevy_camera::prelude::*;
+use bevy_image::Image;
+use bevy_math::{AspectRatio, UVec2};
+use bevy_render::texture::ManualTextureViews;
+use bevy_simple_screen_boxing::{
+    AdjustBoxing, CameraBox, CameraBoxingPlugin, IntegerScaleFit, ResolutionScaleMode,
+};
+use bevy_window::{PrimaryWindow, Window, WindowRef};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+
+const TARGET_SIZES: [UVec2; 4] = [
+    UVec2::new(640, 360),
+    UVec2::new(1366, 768),
+    UVec2::new(1920, 1080),
+    UVec2::new(7680, 4320),
+];
+
+fn modes() -> Vec<(&'static str, CameraBox)> {
+    vec![
+        (
+            "static_resolution",
+            CameraBox::StaticResolution {
+                resolution: UVec2::new(1280, 720),
+                position: None,
+                scale: ResolutionScaleMode::Exact,
+            },
+        ),
+        (
+            "static_aspect_ratio",
+            CameraBox::StaticAspectRatio {
+                aspect_ratio: AspectRatio::try_new(4., 3.).unwrap(),
+                position: None,
+                strict: false,
+            },
+        ),
+        (
+            "resolution_integer_scale",
+            CameraBox::ResolutionIntegerScale {
+                resolution: UVec2::new(320, 180),
+                allow_imperfect_downscaled_boxing: false,
+                independent_axis_scale: false,
+                fit: IntegerScaleFit::FitInside,
+                force_downscale: false,
+                min_content_fraction: None,
+            },
+        ),
+        (
+            "fixed_tiles",
+            CameraBox::FixedTiles {
+                tile_size: UVec2::new(16, 16),
+                cols: 20,
+                rows: 12,
+            },
+        ),
+        (
+            "letterbox",
+            CameraBox::LetterBox {
+                top: 40,
+                bottom: 40,
+                strict_letterboxing: false,
+                preserve_bar_ratio: false,
+                logical_bars: false,
+            },
+        ),
+        (
+            "pillarbox",
+            CameraBox::PillarBox {
+                left: 40,
+                right: 40,
+                strict_pillarboxing: false,
+                logical_bars: false,
+            },
+        ),
+        (
+            "windowbox",
+            CameraBox::WindowBox {
+                left: 40,
+                right: 40,
+                top: 40,
+                bottom: 40,
+                strict_windowboxing: false,
+            },
+        ),
+    ]
+}
+
+fn setup_app(camera_box: CameraBox, target_size: UVec2, cameras: usize) -> App {
+    let mut app = App::new();
+    app.init_resource::<ManualTextureViews>();
+    app.init_resource::<Assets<Image>>();
+    app.add_plugins(CameraBoxingPlugin::default());
+
+    app.world_mut().spawn((
+        Window {
+            resolution: target_size.into(),
+            ..Window::default()
+        },
+        PrimaryWindow,
+    ));
+    for _ in 0..cameras {
+        app.world_mut().spawn((
+            Camera::default(),
+            RenderTarget::Window(WindowRef::Primary),
+            camera_box.clone(),
+        ));
+    }
+
+    // Let the initial boxing settle, so we only measure the frames we care about.
+    app.update();
+    app.update();
+    app
+}
+
+fn bench_modes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("recompute");
+    for (name, camera_box) in modes() {
+        for target_size in TARGET_SIZES {
+            let mut app = setup_app(camera_box.clone(), target_size, 1);
+            group.bench_with_input(
+                BenchmarkId::new(name, format!("{}x{}", target_size.x, target_size.y)),
+                &target_size,
+                |b, _| {
+                    b.iter(|| {
+                        app.world_mut().write_message(AdjustBoxing);
+                        app.update();
+                    })
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+fn many_cameras_app(cameras: usize) -> App {
+    setup_app(
+        CameraBox::StaticAspectRatio {
+            aspect_ratio: AspectRatio::try_new(4., 3.).unwrap(),
+            position: None,
+            strict: false,
+        },
+        UVec2::new(1920, 1080),
+        cameras,
+    )
+}
+
+/// Times `frames` calls to `frame`, returning the fastest since it's the least affected by noise.
+fn fastest_frame(app: &mut App, frames: usize, mut frame: impl FnMut(&mut App)) -> Duration {
+    (0..frames)
+        .map(|_| {
+            let start = Instant::now();
+            frame(app);
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn bench_many_cameras(c: &mut Criterion) {
+    let mut group = c.benchmark_group("many_cameras");
+    for cameras in [10, 100, 1000] {
+        let mut app = many_cameras_app(cameras);
+
+        // Nothing changes, so change detection should skip recalculating the boxes.
+        group.bench_with_input(
+            BenchmarkId::new("steady_state", cameras),
+            &cameras,
+            |b, _| b.iter(|| app.update()),
+        );
+
+        // Every frame recalculates every box, which is what we would do without change detection.
+        group.bench_with_input(BenchmarkId::new("recompute", cameras), &cameras, |b, _| {
+            b.iter(|| {
+                app.world_mut().write_message(AdjustBoxing);
+                app.update();
+            })
+        });
+    }
+    group.finish();
+}
+
+// This isn't measured by criterion, it fails the benches if change detection stops paying off.
+fn assert_steady_state_is_cheaper(_: &mut Criterion) {
+    let mut app = many_cameras_app(1000);
+    let steady_state = fastest_frame(&mut app, 50, |app| app.update());
+    let recompute = fastest_frame(&mut app, 50, |app| {
+        app.world_mut().write_message(AdjustBoxing);
+        app.update();
+    });
+    assert!(
+        steady_state < recompute,
+        "A steady state frame ({steady_state:?}) wasn't cheaper than recomputing every box ({recompute:?})",
+    );
+}
+
+criterion_group!(
+    benches,
+    bench_modes,
+    bench_many_cameras,
+    assert_steady_state_is_cheaper
+);
+criterion_main!(benches);
@@ -0,0 +1,52 @@
+//! Pillarboxes the output with magenta bars, regardless of the camera's clear color.
+
+use bevy::camera::ScalingMode;
+use bevy::prelude::*;
+use bevy::window::WindowResolution;
+use bevy_simple_screen_boxing::{CameraBox, CameraBoxBars, CameraBoxingPlugin};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Pillarbox Bars".into(),
+                resolution: WindowResolution::new(1280, 720),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(CameraBoxingPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    let mut projection = OrthographicProjection::default_2d();
+    projection.scaling_mode = ScalingMode::Fixed {
+        width: 960.,
+        height: 720.,
+    };
+
+    commands.spawn((
+        Camera2d,
+        Camera {
+            clear_color: ClearColorConfig::Custom(Color::srgb(0.5, 0.5, 0.9)),
+            ..default()
+        },
+        CameraBox::PillarBox {
+            left: 160,
+            right: 160,
+            strict_pillarboxing: false,
+            logical_bars: false,
+        },
+        CameraBoxBars::new(Color::srgb(1., 0., 1.)),
+        Projection::Orthographic(projection),
+    ));
+
+    // The bars are cleared to magenta across the whole window, so the content is given its own
+    // background.
+    commands.spawn(Sprite::from_color(
+        Color::srgb(0.5, 0.5, 0.9),
+        Vec2::new(960., 720.),
+    ));
+}
@@ -15,20 +15,700 @@
 //! - Add the `CameraBox` component to your Camera, and configure what you need.
 
 use bevy_app::{App, First, Plugin};
-use bevy_asset::{AssetEvent, Assets};
+use bevy_asset::{AssetEvent, AssetId, Assets};
 use bevy_camera::prelude::*;
-use bevy_camera::{RenderTarget, Viewport};
+use bevy_camera::visibility::RenderLayers;
+use bevy_camera::{
+    ManualTextureViewHandle, NormalizedRenderTarget, RenderTarget, ScalingMode, Viewport,
+};
+use bevy_color::Color;
+use bevy_ecs::entity::EntityHashMap;
 use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemParam;
+#[cfg(feature = "debug_gizmos")]
+use bevy_gizmos::gizmos::Gizmos;
 use bevy_image::Image;
 use bevy_log::{info, warn, warn_once};
-use bevy_math::{AspectRatio, UVec2, Vec2};
+use bevy_math::curve::{Curve, EaseFunction};
+use bevy_math::{AspectRatio, IVec2, Rect, URect, UVec2, UVec4, Vec2};
 use bevy_reflect::Reflect;
 use bevy_render::camera::NormalizedRenderTargetExt;
 use bevy_render::texture::ManualTextureViews;
-use bevy_window::{PrimaryWindow, Window};
+use bevy_time::{Time, TimeSystems, Timer, TimerMode};
+#[cfg(feature = "debug_gizmos")]
+use bevy_transform::components::GlobalTransform;
+use bevy_window::{Monitor, PrimaryWindow, VideoModeSelection, Window, WindowMode};
+use core::time::Duration;
+
+/// How far apart (in ratio) a render target's Aspect Ratio and the Aspect Ratio a `CameraBox` boxes
+/// to can be while still being treated as the same, so the output isn't boxed.
+///
+/// This is only meant to absorb floating point error. It can be changed for every camera with
+/// `CameraBoxingPlugin::aspect_epsilon`, or for a single camera with `AspectTolerance`. Functions
+/// that box without the plugin (like `compute_in_rect`) always use this.
+pub const DEFAULT_ASPECT_EPSILON: f32 = 1e-4;
 
 /// The Plugin that adds in all the systems for camera-boxing.
-pub struct CameraBoxingPlugin;
+#[derive(Default)]
+pub struct CameraBoxingPlugin {
+    /// If true, a one-line summary of a camera's boxing is logged whenever it changes.
+    /// This is meant for debugging, and is off by default.
+    pub log_on_change: bool,
+
+    /// What to do with a camera's viewport when it doesn't need to be boxed.
+    pub on_no_box: NoBoxBehavior,
+
+    /// If true, cameras rendering to a window in exclusive fullscreen at the window's
+    /// current resolution are never boxed. This is off by default.
+    pub unbox_in_exclusive_fullscreen: bool,
+
+    /// The smallest size the boxed output can be, the bars are shrunk to make room if needed.
+    /// This is zero by default, so it has no effect.
+    pub min_content_size: UVec2,
+
+    /// If true, the boxed output always has an even width and height.
+    /// This is off by default.
+    pub even_dimensions: bool,
+
+    /// The thickest any single bar can be, in pixels. The output is grown (cropping the content)
+    /// to keep the bars from being thicker than this.
+    /// This is None by default, so bars can be any size.
+    pub max_bar_size: Option<u32>,
+
+    /// How the boxed output is rounded to whole pixels.
+    /// This is `BoxRounding::Floor` by default.
+    pub rounding: BoxRounding,
+
+    /// How far apart Aspect Ratios can be while still being treated as the same, for cameras
+    /// without an `AspectTolerance`.
+    /// This is None by default, which uses `DEFAULT_ASPECT_EPSILON`.
+    pub aspect_epsilon: Option<f32>,
+
+    /// How many pixels a window's size has to change by (on either axis) before its cameras are
+    /// boxed again. This is meant for window managers that briefly report slightly different
+    /// sizes, which would otherwise make the boxing flicker.
+    /// This is zero by default, so every change is boxed.
+    pub stability_threshold: u32,
+
+    /// If true, debug builds panic when a boxed viewport doesn't fit within its render target or
+    /// has a width or height of zero. This is meant for catching bugs in the boxing math, and is
+    /// off by default. This does nothing in release builds.
+    pub assert_viewports: bool,
+
+    /// The `CameraBox` to add to cameras that are spawned without one.
+    /// This is None by default, so cameras are only boxed if they're given a `CameraBox`.
+    pub default_camera_box: Option<CameraBox>,
+}
+
+impl CameraBoxingPlugin {
+    /// Creates a plugin set up for pixel-art games with a base resolution of `base`.
+    ///
+    /// Every camera spawned without a `CameraBox` is given a `CameraBox::ResolutionIntegerScale`
+    /// of `base`, which keeps the pixels square and the output at integer offsets. You will likely
+    /// also want to use nearest sampling for your images (e.g. `ImagePlugin::default_nearest()`).
+    pub fn pixel_art(base: UVec2) -> Self {
+        Self {
+            default_camera_box: Some(CameraBox::ResolutionIntegerScale {
+                resolution: base,
+                allow_imperfect_downscaled_boxing: false,
+                independent_axis_scale: false,
+                fit: IntegerScaleFit::FitInside,
+                force_downscale: false,
+                min_content_fraction: None,
+            }),
+            ..Self::default()
+        }
+    }
+
+    /// Sets what to do with a camera's viewport when it doesn't need to be boxed.
+    pub fn on_no_box(mut self, behavior: NoBoxBehavior) -> Self {
+        self.on_no_box = behavior;
+        self
+    }
+
+    /// Sets how far apart Aspect Ratios can be while still being treated as the same, for cameras
+    /// without an `AspectTolerance`.
+    pub fn aspect_epsilon(mut self, epsilon: f32) -> Self {
+        self.aspect_epsilon = Some(epsilon);
+        self
+    }
+}
+
+/// Overrides the size of the render target the next time the camera's boxing is calculated.
+///
+/// This is useful when you know the size the render target is about to become, for example when
+/// switching to fullscreen, and want to box for it before the switch happens. The plugin sets this
+/// back to None once the boxing has been calculated.
+#[derive(Component, Reflect, Default, Clone, PartialEq, Debug)]
+#[reflect(Component)]
+pub struct PendingTargetSize(pub Option<UVec2>);
+
+/// Where to place the boxed output within the render target, instead of centering it.
+///
+/// Currently this is only used by `CameraBox::StaticResolution` and `CameraBox::StaticAspectRatio`
+/// when they have no `position`, and by `CameraBox::ResolutionIntegerScale`,
+/// `CameraBox::FixedTiles`, and `CameraBox::IntegerAspect`. For example, `BoxAnchor::Bottom` keeps the output flush with the
+/// bottom of the render target and puts all the letterboxing at the top, which is useful for games
+/// where the ground should always be at the bottom of the screen.
+#[derive(Component, Reflect, Default, Clone, Copy, PartialEq, Debug)]
+#[reflect(Component)]
+pub enum BoxAnchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    #[default]
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+
+    /// Keep a point of the output, given in normalized coordinates (0 to 1, from the top left), at
+    /// the same normalized position in the render target. For example, `Focus(Vec2::new(0.25, 0.5))`
+    /// keeps the point a quarter of the way across the output a quarter of the way across the
+    /// render target, no matter the size of either.
+    Focus(Vec2),
+}
+
+impl BoxAnchor {
+    /// Returns how far along each axis the output is placed, from 0 (top/left) to 1 (bottom/right).
+    pub fn factor(&self) -> Vec2 {
+        match self {
+            BoxAnchor::TopLeft => Vec2::new(0., 0.),
+            BoxAnchor::Top => Vec2::new(0.5, 0.),
+            BoxAnchor::TopRight => Vec2::new(1., 0.),
+            BoxAnchor::Left => Vec2::new(0., 0.5),
+            BoxAnchor::Center => Vec2::new(0.5, 0.5),
+            BoxAnchor::Right => Vec2::new(1., 0.5),
+            BoxAnchor::BottomLeft => Vec2::new(0., 1.),
+            BoxAnchor::Bottom => Vec2::new(0.5, 1.),
+            BoxAnchor::BottomRight => Vec2::new(1., 1.),
+            BoxAnchor::Focus(focus) => focus.clamp(Vec2::ZERO, Vec2::ONE),
+        }
+    }
+
+    /// Returns the offset of output placed at this anchor within the render target.
+    pub fn offset(&self, target_size: Vec2, output_resolution: Vec2) -> Vec2 {
+        ((target_size - output_resolution) * self.factor()).max(Vec2::ZERO)
+    }
+}
+
+/// Places the boxed output at a `BoxAnchor`, moved by a signed offset.
+///
+/// This is used in the same places as `BoxAnchor`, and is used instead of it if both are present.
+/// The output is always kept within the render target. For example, an anchor of
+/// `BoxAnchor::TopRight` with an offset of `IVec2::new(-20, 20)` places the output 20 pixels in
+/// from the top and right edges.
+#[derive(Component, Reflect, Default, Clone, Copy, PartialEq, Debug)]
+#[reflect(Component)]
+pub struct AnchoredOffset {
+    pub anchor: BoxAnchor,
+    pub offset: IVec2,
+}
+
+impl AnchoredOffset {
+    /// Returns the offset of output placed at this anchor and offset within the render target.
+    pub fn offset(&self, target_size: Vec2, output_resolution: Vec2) -> Vec2 {
+        (self.anchor.offset(target_size, output_resolution) + self.offset.as_vec2()).clamp(
+            Vec2::ZERO,
+            (target_size - output_resolution).max(Vec2::ZERO),
+        )
+    }
+}
+
+/// Moves a boxed camera's viewport by `offset` on top of its boxing, for effects like screen shake.
+///
+/// This is applied every frame after the boxing has been calculated, without recalculating the
+/// boxing, and the viewport is always kept within the render target. Cameras that aren't boxed
+/// have no room to move, so this does nothing for them.
+#[derive(Component, Reflect, Default, Clone, Copy, PartialEq, Debug)]
+#[reflect(Component)]
+pub struct ViewportShake {
+    pub offset: IVec2,
+}
+
+/// The smallest a camera's boxed output can be, and what to do if the boxing would make it smaller.
+///
+/// This is checked after the boxing (and `CameraBoxingSettings::min_content_size`) is applied, and
+/// is meant to stop tiny windows from producing viewports that are too small to be useful.
+#[derive(Component, Reflect, Default, Clone, Copy, PartialEq, Debug)]
+#[reflect(Component)]
+pub struct MinViewportSize {
+    pub size: UVec2,
+    pub behavior: MinViewportBehavior,
+}
+
+/// What `MinViewportSize` does when the boxed output would be smaller than its size.
+#[derive(Reflect, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MinViewportBehavior {
+    /// Don't box the camera at all, so it renders to the whole render target.
+    #[default]
+    Disable,
+
+    /// Grow the boxed output (around its center) to the minimum size, without growing past the
+    /// render target. A warning is logged the first time this happens.
+    Clamp,
+}
+
+/// How far apart (in ratio) the render target's Aspect Ratio and the Aspect Ratio the camera's
+/// `CameraBox` boxes to can be while still being treated as the same, so the output isn't boxed.
+///
+/// This overrides `CameraBoxingSettings::aspect_epsilon` for the camera it's on.
+#[derive(Component, Reflect, Clone, Copy, PartialEq, Debug)]
+#[reflect(Component)]
+pub struct AspectTolerance(pub f32);
+
+/// The scale factor to use for the camera's logical units (like `CameraBox::LetterBox`'s
+/// `logical_bars`), instead of the render target's scale factor.
+///
+/// This is meant for testing, and for apps that manage their own DPI scaling.
+#[derive(Component, Reflect, Clone, Copy, PartialEq, Debug)]
+#[reflect(Component)]
+pub struct ScaleFactorOverride(pub f32);
+
+/// The `CameraBox` to use for a camera while it isn't active.
+///
+/// Cameras are normally left alone while `Camera::is_active` is false, but with this they're
+/// boxed with the `CameraBox` here instead (or have their viewport cleared if it's None). This is
+/// meant for setups that cycle between cameras, where the inactive ones are shown elsewhere (like
+/// in thumbnails) and shouldn't have bars.
+#[derive(Component, Reflect, Clone, PartialEq, Debug)]
+#[reflect(Component)]
+pub struct InactiveBox(pub Option<CameraBox>);
+
+/// Links cameras so they all share the exact same boxing.
+///
+/// Cameras with the same group that render to the same render target are all given the boxing of
+/// the group's leader, which is the active camera with the lowest `order` (ties are broken by the
+/// entity). This keeps cameras that are drawn on top of each other (like a world and UI camera)
+/// aligned, even if their `CameraBox`es would box them slightly differently.
+#[derive(Component, Reflect, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[reflect(Component)]
+pub struct CameraBoxGroup(pub u32);
+
+/// Turns the boxing of a camera on or off, without removing its `CameraBox`.
+///
+/// While this is false, the camera's viewport is cleared and it is not boxed. Cameras without
+/// this are always boxed.
+#[derive(Component, Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+#[reflect(Component)]
+pub struct CameraBoxEnabled(pub bool);
+
+impl Default for CameraBoxEnabled {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+/// Draws the boxed output and the bars of a camera with gizmos, for debugging the boxing.
+///
+/// The outline of the boxed output is drawn, along with each bar (crossed out). The gizmos are
+/// drawn in the camera's 2D world space, so the bars are only visible through cameras that aren't
+/// limited to the boxed viewport (such as a 2D camera covering the whole render target with the
+/// same transform).
+///
+/// This requires the `debug_gizmos` feature.
+#[cfg(feature = "debug_gizmos")]
+#[derive(Component, Reflect, Clone, Copy, PartialEq, Debug)]
+#[reflect(Component)]
+pub struct CameraBoxDebug {
+    /// The color to draw everything in.
+    pub color: Color,
+}
+
+/// Fills the bars of a boxed camera with a solid color.
+///
+/// This spawns a camera (with a `BoxBarsFor`) that renders nothing, and only clears the render
+/// target to the color. Since clearing covers the whole render target, that camera is ordered
+/// below every other camera rendering to the same render target by default, so it never clears
+/// over them. The boxed camera keeps its own `clear_color`, which clears its viewport.
+#[derive(Component, Reflect, Clone, Copy, PartialEq, Debug)]
+#[reflect(Component)]
+pub struct CameraBoxBars {
+    /// The color to fill the bars with.
+    pub color: Color,
+
+    /// The `order` of the camera drawing the bars. If this is None, it's ordered below every other
+    /// camera rendering to the same render target.
+    ///
+    /// Any camera rendering to the same render target with a lower order is cleared over by the
+    /// bars, so this should only be set when the cameras below it clear their own viewport (or when
+    /// that's the intent).
+    pub order: Option<isize>,
+}
+
+impl CameraBoxBars {
+    /// Fills the bars with `color`, drawn below every other camera on the render target.
+    pub fn new(color: Color) -> Self {
+        Self { color, order: None }
+    }
+}
+
+/// The camera drawing the bars for a camera with `CameraBoxBars`.
+///
+/// This is spawned and despawned by the plugin, and shouldn't need to be added manually.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct BoxBarsFor {
+    /// The camera whose bars are being drawn.
+    pub camera: Entity,
+}
+
+/// Smoothly changes a camera's Aspect Ratio from `from` to `to` over the timer's duration.
+///
+/// While this is on a camera, its `CameraBox` is replaced every frame with a
+/// `CameraBox::StaticAspectRatio` of the current Aspect Ratio (keeping the `position` and `strict`
+/// of an existing `CameraBox::StaticAspectRatio`). Once the timer finishes, the camera is left at
+/// `to` and the plugin removes this component.
+///
+/// The ratio is interpolated geometrically (linearly in log space) rather than linearly, so going
+/// from 16:9 to 21:9 and back again follows the same path, and widening happens at the same rate
+/// as narrowing.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct AspectRatioTween {
+    /// The Aspect Ratio to start at.
+    pub from: AspectRatio,
+
+    /// The Aspect Ratio to end at.
+    pub to: AspectRatio,
+
+    /// How far along the change is, this is ticked by the plugin.
+    pub timer: Timer,
+}
+
+impl AspectRatioTween {
+    /// Creates an `AspectRatioTween` which changes from `from` to `to` over `duration`.
+    pub fn new(from: AspectRatio, to: AspectRatio, duration: Duration) -> Self {
+        Self {
+            from,
+            to,
+            timer: Timer::new(duration, TimerMode::Once),
+        }
+    }
+
+    /// Returns the Aspect Ratio the camera is currently at.
+    pub fn current(&self) -> AspectRatio {
+        let progress = self.timer.fraction();
+        let ratio = bevy_math::ops::powf(self.from.ratio(), 1. - progress)
+            * bevy_math::ops::powf(self.to.ratio(), progress);
+        AspectRatio::try_new(ratio, 1.).unwrap_or(self.to)
+    }
+}
+
+/// The viewport a camera had before it was given a `CameraBox`.
+///
+/// This is inserted by the plugin when a `CameraBox` is added to a camera, and when the
+/// `CameraBox` is removed the camera's viewport is set back to it and this is removed.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct OriginalViewport(pub Option<Viewport>);
+
+impl PartialEq for OriginalViewport {
+    fn eq(&self, other: &Self) -> bool {
+        viewport_key(&self.0) == viewport_key(&other.0)
+    }
+}
+
+/// Reveals the boxed output by growing it from the center, like an iris opening.
+///
+/// The output starts at `from_fraction` of its size, and grows to its full size over `duration`.
+/// The boxing is recalculated every frame while this is happening, so the render target can be
+/// resized during the reveal. Once it's done, the plugin removes this component.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct IrisReveal {
+    /// How big the output starts, as a fraction of its full size.
+    pub from_fraction: f32,
+
+    /// How long the reveal takes.
+    pub duration: Duration,
+
+    /// How the output grows over the duration.
+    pub easing: EaseFunction,
+
+    /// How long the reveal has been going for, this is updated by the plugin.
+    pub elapsed: Duration,
+}
+
+impl IrisReveal {
+    /// Creates an `IrisReveal` which grows the output from `from_fraction` of its size to its
+    /// full size over `duration`.
+    pub fn new(from_fraction: f32, duration: Duration, easing: EaseFunction) -> Self {
+        Self {
+            from_fraction,
+            duration,
+            easing,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Returns the fraction of the full size the output currently is.
+    pub fn fraction(&self) -> f32 {
+        let progress = if self.duration.is_zero() {
+            1.
+        } else {
+            self.elapsed.as_secs_f32() / self.duration.as_secs_f32()
+        };
+        let from_fraction = self.from_fraction.clamp(0., 1.);
+        from_fraction + (1. - from_fraction) * self.easing.sample_clamped(progress)
+    }
+
+    /// Shrinks `boxing` around its center to the current fraction of its size.
+    pub fn shrink(&self, boxing: Boxing) -> Boxing {
+        let output_resolution = boxing.output_resolution * self.fraction();
+        Boxing {
+            boxing_offset: boxing.boxing_offset
+                + (boxing.output_resolution - output_resolution) / 2.,
+            output_resolution,
+        }
+    }
+}
+
+/// Smoothly moves a camera's viewport to its new boxing whenever the boxing changes, instead of
+/// snapping to it.
+///
+/// The viewport is moved from where it was to the new boxing over `duration`, and ends up exactly
+/// at the new boxing. The first boxing calculated for a camera is used straight away.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct CameraBoxTransition {
+    /// How long moving to the new boxing takes.
+    pub duration: Duration,
+
+    /// How the viewport moves over the duration.
+    pub easing: EaseFunction,
+
+    /// How long the current transition has been going for, this is updated by the plugin.
+    pub elapsed: Duration,
+
+    #[reflect(ignore)]
+    from: Option<Boxing>,
+
+    #[reflect(ignore)]
+    to: Option<Boxing>,
+}
+
+impl CameraBoxTransition {
+    /// Creates a `CameraBoxTransition` which takes `duration` to move to the new boxing.
+    pub fn new(duration: Duration, easing: EaseFunction) -> Self {
+        Self {
+            duration,
+            easing,
+            elapsed: Duration::ZERO,
+            from: None,
+            to: None,
+        }
+    }
+
+    /// Returns true if the viewport has reached the boxing it is moving to.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration || self.from == self.to
+    }
+
+    /// Returns the boxing the viewport should currently have, if any boxing has been calculated.
+    pub fn current(&self) -> Option<Boxing> {
+        let (from, to) = (self.from?, self.to?);
+        if self.is_finished() {
+            return Some(to);
+        }
+        let progress = self.elapsed.as_secs_f32() / self.duration.as_secs_f32();
+        Some(from.lerp(&to, self.easing.sample_clamped(progress)))
+    }
+
+    /// Starts moving to `target` if it's different to the boxing being moved to, and returns the
+    /// boxing the viewport should currently have.
+    fn retarget(&mut self, target: Boxing) -> Boxing {
+        if self.to != Some(target) {
+            self.from = Some(self.current().unwrap_or(target));
+            self.to = Some(target);
+            self.elapsed = Duration::ZERO;
+        }
+        self.current().unwrap_or(target)
+    }
+}
+
+/// Temporarily replaces a camera's `CameraBox` until the timer finishes.
+///
+/// When this is inserted, the camera's current `CameraBox` is stored in `previous` and replaced
+/// with `mode`. Once the timer finishes, `previous` is put back (or the `CameraBox` is removed, if
+/// there wasn't one) and this component is removed.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component)]
+pub struct TemporaryBox {
+    /// The `CameraBox` to use while the timer is running.
+    pub mode: CameraBox,
+
+    /// How long to use `mode` for.
+    pub timer: Timer,
+
+    /// The `CameraBox` to restore once the timer finishes.
+    /// This is set by the plugin when the component is inserted.
+    pub previous: Option<CameraBox>,
+}
+
+impl TemporaryBox {
+    /// Creates a `TemporaryBox` which uses `mode` for `duration`.
+    pub fn new(mode: CameraBox, duration: Duration) -> Self {
+        Self {
+            mode,
+            timer: Timer::new(duration, TimerMode::Once),
+            previous: None,
+        }
+    }
+
+    /// Creates a `TemporaryBox` which boxes to the Aspect Ratio of a video with the size
+    /// `video_size` for `duration`, after which the camera's previous `CameraBox` is restored.
+    pub fn cutscene(video_size: UVec2, duration: Duration) -> Self {
+        Self::new(CameraBox::CutsceneLetterbox { video_size }, duration)
+    }
+}
+
+/// While this is true, the boxing of every camera is left alone so that viewports can be moved by
+/// hand (e.g. in a layout editor).
+///
+/// Changes are still detected while this is true, and the boxing is recalculated once it is set
+/// back to false. This is inserted by `CameraBoxingPlugin`, and is false by default.
+#[derive(Resource, Reflect, Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[reflect(Resource)]
+pub struct LayoutEditing(pub bool);
+
+/// Whether the boxing changed the viewport of any camera this frame.
+///
+/// This is reset at the start of every frame, and is meant to be polled (e.g. with
+/// `run_if(resource_equals(BoxingChangedThisFrame(true)))`) instead of reading `CameraBoxed`.
+/// This is inserted by `CameraBoxingPlugin`.
+#[derive(Resource, Reflect, Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[reflect(Resource)]
+pub struct BoxingChangedThisFrame(pub bool);
+
+/// Settings that apply to all boxed cameras.
+/// This is inserted by `CameraBoxingPlugin`, based on how the plugin was configured.
+#[derive(Resource, Reflect, Default, Clone, Debug)]
+#[reflect(Resource)]
+pub struct CameraBoxingSettings {
+    /// What to do with a camera's viewport when it doesn't need to be boxed.
+    pub on_no_box: NoBoxBehavior,
+
+    /// If true, cameras rendering to a window in exclusive fullscreen at the window's
+    /// current resolution have their viewport cleared instead of being boxed.
+    pub unbox_in_exclusive_fullscreen: bool,
+
+    /// The smallest size the boxed output can be. If the boxed output would be smaller than this,
+    /// it is grown (around its center) and the bars are shrunk, but it never grows past the render
+    /// target.
+    pub min_content_size: UVec2,
+
+    /// If true, the boxed output is shrunk by a pixel when its width or height is odd, and the
+    /// pixel is given to the smaller bar. This is useful when capturing the output, as many video
+    /// encoders require even dimensions.
+    pub even_dimensions: bool,
+
+    /// The thickest any single bar can be, in pixels. If a bar would be thicker than this, then it
+    /// is shrunk to this size and the boxed output is grown to fill the difference, which crops
+    /// the content instead of boxing it further.
+    pub max_bar_size: Option<u32>,
+
+    /// How the boxed output is rounded to whole pixels when it's turned into a viewport.
+    pub rounding: BoxRounding,
+
+    /// How far apart a render target's Aspect Ratio and the Aspect Ratio a `CameraBox` boxes to
+    /// (including nested boxes) can be while still being treated as the same, for cameras without
+    /// an `AspectTolerance`. If this is None, then `DEFAULT_ASPECT_EPSILON` is used.
+    pub aspect_epsilon: Option<f32>,
+
+    /// How many pixels a window's size has to change by (on either axis), compared to the size
+    /// its cameras were last boxed for, before they're boxed again. Smaller changes are ignored
+    /// until they add up past this, so the boxing can be off by up to this many pixels.
+    /// If this is zero, then every change is boxed.
+    pub stability_threshold: u32,
+
+    /// If true, debug builds panic when a boxed viewport doesn't fit within its render target or
+    /// has a width or height of zero (unless an `IrisReveal` is shrinking it). This does nothing in
+    /// release builds.
+    pub assert_viewports: bool,
+
+    /// The `CameraBox` to add to cameras that are spawned without one.
+    pub default_camera_box: Option<CameraBox>,
+}
+
+/// How `CameraBox::ResolutionIntegerScale` picks the Integer Scale to use.
+#[derive(Reflect, Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IntegerScaleFit {
+    /// Use the largest Integer Scale that fits entirely inside the output.
+    #[default]
+    FitInside,
+
+    /// Use the Integer Scale whose area is closest to the area of the output.
+    ///
+    /// If the next Integer Scale up is closer, then the output is not boxed at all and the content
+    /// will be cropped, this makes better use of the screen at the cost of losing some of the edges.
+    AreaMatch,
+
+    /// Use the Integer Scale closest to how much the resolution can be scaled while fitting inside
+    /// the output, even if it's larger than the output.
+    ///
+    /// If the scale is larger than the output, then the boxed output is clamped to the output and
+    /// the content is cropped on that axis, while any other axis is still boxed. This is only used
+    /// when scaling up.
+    Nearest,
+}
+
+/// How `CameraBox::StaticResolution` scales its resolution to the output.
+#[derive(Reflect, Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ResolutionScaleMode {
+    /// Keep the output at exactly the resolution, boxing it if the output is larger.
+    #[default]
+    Exact,
+
+    /// Scale the resolution up by the largest Integer Scale that fits in the output.
+    /// If the output is smaller than the resolution, this is the same as `Exact`.
+    IntegerFit,
+
+    /// Scale the resolution to fill as much of the output as it can, while keeping the
+    /// resolution's Aspect Ratio.
+    StretchFit,
+}
+
+/// What to do with a camera's viewport when it doesn't need to be boxed.
+#[derive(Reflect, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NoBoxBehavior {
+    /// Set the viewport to `None`, so that the camera renders to the whole render target.
+    #[default]
+    ClearViewport,
+
+    /// Leave the viewport as it is.
+    /// Note that this means if a camera was boxed before, it will stay boxed the same way. The
+    /// viewport is still cleared if the render target's Aspect Ratio is degenerate (e.g. it has a
+    /// width of zero), since it was calculated for a size that no longer exists.
+    LeaveViewport,
+}
+
+/// How the boxed output is rounded to whole pixels.
+///
+/// The boxing is calculated with sub-pixel precision, which can leave the bars on either side of
+/// the output a pixel or two apart once it's turned into a viewport.
+#[derive(Reflect, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BoxRounding {
+    /// Round the position and size of the output down.
+    #[default]
+    Floor,
+
+    /// Round the position and size of the output to the nearest pixel.
+    Round,
+
+    /// Round the position and size of the output up, without going past the render target.
+    Ceil,
+
+    /// Round the size of the output to the nearest pixel, and split the leftover pixels between the
+    /// bars so they differ by at most one pixel and exactly fill the render target.
+    ///
+    /// This only applies to axes where the output is centered, any other axis is rounded down.
+    SymmetricPad,
+}
 
 /// The system set provided and used by the plugin for ordering.
 #[derive(SystemSet, Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -46,6 +726,27 @@ pub enum CameraBoxSet {
 /// This event is used to tell us that we need to recalculate our Camera Boxes.
 pub struct AdjustBoxing;
 
+#[derive(Message)]
+/// This event is used to tell us that we need to recalculate the Camera Box of a single camera.
+/// If `AdjustBoxing` is also sent in the same frame, then this is ignored as every camera will be
+/// recalculated anyway.
+pub struct AdjustBoxingFor(pub Entity);
+
+#[derive(Message, Clone, Debug)]
+/// This event is sent when a camera could not be boxed the way its `CameraBox` asked for.
+pub enum BoxingError {
+    /// The render target did not match the aspect ratio of a strict `CameraBox::StaticAspectRatio`.
+    AspectMismatch {
+        camera: Entity,
+        expected: AspectRatio,
+        actual: AspectRatio,
+    },
+
+    /// The image the camera renders to could not be found, usually because it was removed.
+    /// The camera's viewport is cleared when this happens.
+    TargetMissing { camera: Entity },
+}
+
 #[derive(Component, Reflect, Debug)]
 #[reflect(Component)]
 #[relationship(relationship_target=HasNested)]
@@ -61,7 +762,58 @@ pub struct HasNested(Entity);
 impl Plugin for CameraBoxingPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<CameraBox>()
+            .register_type::<AspectRatio>()
+            .register_type::<ComputedBoxing>()
+            .register_type::<CameraBoxingSettings>()
+            .register_type::<PendingTargetSize>()
+            .register_type::<TemporaryBox>()
+            .register_type::<AspectRatioTween>()
+            .register_type::<BoxAnchor>()
+            .register_type::<AnchoredOffset>()
+            .register_type::<IrisReveal>()
+            .register_type::<CameraBoxTransition>()
+            .register_type::<ViewportShake>()
+            .register_type::<CameraBoxEnabled>()
+            .register_type::<CameraBoxGroup>()
+            .register_type::<AspectTolerance>()
+            .register_type::<InactiveBox>()
+            .register_type::<ScaleFactorOverride>()
+            .register_type::<OriginalViewport>()
+            .register_type::<MinViewportSize>()
+            .register_type::<MinViewportBehavior>()
+            .register_type::<CameraBoxBars>()
+            .register_type::<BoxBarsFor>()
+            .register_type::<NoBoxBehavior>()
+            .register_type::<BoxRounding>()
+            .register_type::<IntegerScaleFit>()
+            .register_type::<ResolutionScaleMode>()
+            .register_type::<BoxedAxes>()
+            .register_type::<NestedWithin>()
+            .register_type::<HasNested>()
+            .register_type::<BoxingDebugSnapshot>()
+            .register_type::<CameraBoxDebugEntry>()
+            .register_type::<LayoutEditing>()
+            .register_type::<BoxingState>()
+            .init_resource::<LayoutEditing>()
+            .register_type::<BoxingChangedThisFrame>()
+            .init_resource::<BoxingChangedThisFrame>()
+            .insert_resource(CameraBoxingSettings {
+                on_no_box: self.on_no_box,
+                unbox_in_exclusive_fullscreen: self.unbox_in_exclusive_fullscreen,
+                min_content_size: self.min_content_size,
+                even_dimensions: self.even_dimensions,
+                max_bar_size: self.max_bar_size,
+                rounding: self.rounding,
+                aspect_epsilon: self.aspect_epsilon,
+                stability_threshold: self.stability_threshold,
+                assert_viewports: self.assert_viewports,
+                default_camera_box: self.default_camera_box.clone(),
+            })
             .add_message::<AdjustBoxing>()
+            .add_message::<AdjustBoxingFor>()
+            .add_message::<BoxingError>()
+            .add_message::<BoxingToggled>()
+            .add_message::<CameraBoxed>()
             .configure_sets(
                 First,
                 (
@@ -73,7 +825,13 @@ impl Plugin for CameraBoxingPlugin {
             )
             .add_systems(
                 First,
-                (windows_changed, camerabox_changed).in_set(CameraBoxSet::DetectChanges),
+                (
+                    windows_changed,
+                    camerabox_changed,
+                    activity_changed,
+                    store_original_viewports,
+                )
+                    .in_set(CameraBoxSet::DetectChanges),
             )
             .add_systems(
                 First,
@@ -88,16 +846,130 @@ impl Plugin for CameraBoxingPlugin {
                     .in_set(CameraBoxSet::DetectChanges)
                     .run_if(resource_changed_or_removed::<ManualTextureViews>),
             )
+            .add_systems(
+                First,
+                settings_changed.in_set(CameraBoxSet::DetectChanges).run_if(
+                    resource_changed::<CameraBoxingSettings>
+                        .or_else(resource_changed::<LayoutEditing>),
+                ),
+            )
             .add_systems(
                 First,
                 adjust_viewport.in_set(CameraBoxSet::RecalculateBoxes),
+            )
+            .add_systems(
+                First,
+                adjust_viewport_for
+                    .after(CameraBoxSet::DetectChanges)
+                    .before(CameraBoxSet::RecalculateBoxes)
+                    .run_if(
+                        on_message::<AdjustBoxingFor>.and_then(not(on_message::<AdjustBoxing>)),
+                    ),
+            );
+
+        app.add_systems(
+            First,
+            reset_boxing_changed.before(CameraBoxSet::DetectChanges),
+        );
+
+        app.add_systems(
+            First,
+            insert_default_camera_box
+                .before(CameraBoxSet::DetectChanges)
+                .run_if(|settings: Res<CameraBoxingSettings>| {
+                    settings.default_camera_box.is_some()
+                }),
+        );
+
+        app.add_systems(
+            First,
+            update_aspect_ratio_tweens
+                .after(TimeSystems)
+                .before(CameraBoxSet::DetectChanges)
+                .run_if(resource_exists::<Time>),
+        );
+
+        app.add_systems(
+            First,
+            update_iris_reveals
+                .after(TimeSystems)
+                .before(CameraBoxSet::DetectChanges)
+                .run_if(resource_exists::<Time>),
+        );
+
+        app.add_systems(
+            First,
+            update_camera_box_transitions
+                .after(TimeSystems)
+                .before(CameraBoxSet::DetectChanges)
+                .run_if(resource_exists::<Time>),
+        );
+
+        app.add_systems(
+            First,
+            update_temporary_boxes
+                .after(TimeSystems)
+                .before(CameraBoxSet::DetectChanges)
+                .run_if(resource_exists::<Time>),
+        );
+
+        app.add_systems(
+            First,
+            apply_viewport_shake.after(CameraBoxSet::RecalculateBoxes),
+        );
+
+        app.add_systems(
+            First,
+            sync_vertical_extents.before(CameraBoxSet::DetectChanges),
+        );
+
+        app.add_systems(
+            First,
+            restore_original_viewports.after(CameraBoxSet::RecalculateBoxes),
+        );
+
+        app.add_systems(
+            First,
+            (spawn_box_bars, sync_box_bars, remove_box_bars)
+                .chain()
+                .after(CameraBoxSet::RecalculateBoxes),
+        );
+
+        app.add_systems(
+            First,
+            update_debug_snapshot
+                .after(CameraBoxSet::RecalculateBoxes)
+                .run_if(resource_exists::<BoxingDebugSnapshot>),
+        );
+
+        if self.log_on_change {
+            app.add_systems(
+                First,
+                log_boxing_changes.after(CameraBoxSet::RecalculateBoxes),
             );
+        }
+
+        #[cfg(feature = "debug_gizmos")]
+        app.register_type::<CameraBoxDebug>()
+            .add_systems(bevy_app::PostUpdate, draw_boxing_debug);
     }
 }
 
-#[derive(Component, Reflect)]
+#[derive(Component, Reflect, Clone, PartialEq, Debug)]
 #[reflect(Component)]
+#[require(ComputedBoxing)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Configures how to box the output, with either: PillarBoxes, Letterboxes, or both.
+///
+/// Adding this to a camera (whether it's spawned with it, or it's added later) also adds
+/// `ComputedBoxing`.
+///
+/// If the camera has a `SubCameraView`, the output is boxed within the part of the render target
+/// the sub view covers, scaled from the sub view's `full_size` to the render target, instead of
+/// the full render target. Any viewport set manually on such a camera is replaced by the boxed one,
+/// and a warning is logged once.
+///
+/// With the `serde` feature, this implements `Serialize` and `Deserialize`.
 pub enum CameraBox {
     /// Keep the output at a static resolution, if possible, and box if it exceeds the resolution.
     /// If the output is smaller than the resolution, it will output at the smaller resolution
@@ -108,22 +980,31 @@ pub enum CameraBox {
         /// Where to put the Boxed output, if this is None then it will be centered.
         /// If the output is not boxed, this will not be used.
         position: Option<UVec2>,
+
+        /// How to scale the resolution to the output.
+        scale: ResolutionScaleMode,
     },
 
     /// Keep the output as a static Aspect Ratio. If the output is not at the Aspect Ratio apply
     /// boxing to force it into the correct Aspect Ratio.
     StaticAspectRatio {
+        #[cfg_attr(feature = "serde", serde(with = "aspect_ratio_serde"))]
         aspect_ratio: AspectRatio,
 
         /// Where to put the Boxed output, if this is None then it will be centered.
         /// If the output is not boxed, then this will not be used.
         position: Option<UVec2>,
+
+        /// If this is true, then instead of boxing the output when the Aspect Ratio doesn't match,
+        /// the viewport is cleared and a `BoxingError::AspectMismatch` is sent.
+        /// This is meant for deployments where the display is known ahead of time.
+        strict: bool,
     },
 
     /// Keep the output at an Integer Scale of a specific Resolution, if no Integer Scale exists
     /// box the output to an Integer Scale.
     ResolutionIntegerScale {
-        resolution: Vec2,
+        resolution: UVec2,
 
         /// If this is true, then the output may not be *exactly* the proper Aspect Ratio if the
         /// output resolution is smaller than the resolution specified, this will result in only
@@ -136,6 +1017,57 @@ pub enum CameraBox {
         /// If the output resolution is expected to larger than, or equal to, the resolution
         /// specified then this does not matter.
         allow_imperfect_downscaled_boxing: bool,
+
+        /// If this is true, then each axis is integer scaled on its own, rather than both axes
+        /// using the same scale. This fills more of the render target, at the cost of the pixels
+        /// no longer being square (e.g. 320x180 on 1700x720 is scaled 5x wide and 4x tall).
+        ///
+        /// If this is true, then `allow_imperfect_downscaled_boxing` is not used.
+        independent_axis_scale: bool,
+
+        /// How to pick the Integer Scale when the output is larger than the resolution.
+        fit: IntegerScaleFit,
+
+        /// If this is true, then the resolution is never scaled up, and is instead divided by the
+        /// smallest integer that makes it fit in the output. This is meant for rendering at a
+        /// high resolution and downscaling it (e.g. 1280x720 on 640x360 is divided by 2).
+        ///
+        /// If this is true, then `allow_imperfect_downscaled_boxing`, `independent_axis_scale`,
+        /// and `fit` are not used.
+        force_downscale: bool,
+
+        /// The smallest fraction (from 0 to 1) of the output's area that the scaled resolution can
+        /// cover. If the largest Integer Scale that fits covers less than this, then the output
+        /// is not boxed at all, and the content is cropped instead (like `IntegerScaleFit::AreaMatch`).
+        ///
+        /// This is only used when scaling up, and not when `independent_axis_scale` or
+        /// `force_downscale` is true.
+        min_content_fraction: Option<f32>,
+    },
+
+    /// Keep a fixed number of tiles visible, at an Integer Scale.
+    /// This is the same as `ResolutionIntegerScale` with a resolution of `tile_size * (cols, rows)`
+    /// that doesn't allow imperfect downscaled boxing.
+    FixedTiles {
+        /// The size of a single tile, in pixels.
+        tile_size: UVec2,
+
+        /// How many tiles should be visible horizontally.
+        cols: u32,
+
+        /// How many tiles should be visible vertically.
+        rows: u32,
+    },
+
+    /// Keep the output at the Aspect Ratio of `base`, at the largest Integer Scale of `base` that
+    /// fits in the output.
+    ///
+    /// Unlike `ResolutionIntegerScale`, this never scales by anything but a whole number. If the
+    /// output is smaller than `base`, then it is boxed to the largest size at `base`'s Aspect Ratio
+    /// that fits instead.
+    IntegerAspect {
+        /// The resolution to scale, which also sets the Aspect Ratio.
+        base: UVec2,
     },
 
     /// Have static letterboxing with specific sizes for each of the bars.
@@ -150,6 +1082,17 @@ pub enum CameraBox {
         /// size of the letterboxes. If this is true, then letterboxing will be disabled in the
         /// cases where it would be smaller.
         strict_letterboxing: bool,
+
+        /// If true, scaling the letterboxing down keeps the ratio between the top and bottom
+        /// bars, instead of centering the output.
+        /// This does nothing if `strict_letterboxing` is true.
+        preserve_bar_ratio: bool,
+
+        /// If true, `top` and `bottom` are in logical pixels instead of physical pixels. They're
+        /// scaled by the render target's scale factor (or the camera's `ScaleFactorOverride`) and
+        /// rounded to whole physical pixels together, so bars of the same size are never more than
+        /// a pixel apart.
+        logical_bars: bool,
     },
 
     /// Have static Pillarboxing with specific sizes for each of the bars.
@@ -164,6 +1107,12 @@ pub enum CameraBox {
         /// size of the pillarboxes. If this is true, then pillarboxing will be disabled in the
         /// cases where it would be smaller.
         strict_pillarboxing: bool,
+
+        /// If true, `left` and `right` are in logical pixels instead of physical pixels. They're
+        /// scaled by the render target's scale factor (or the camera's `ScaleFactorOverride`) and
+        /// rounded to whole physical pixels together, so bars of the same size are never more than
+        /// a pixel apart.
+        logical_bars: bool,
     },
 
     /// Have static Windowboxing with specific sizes for each of the bars.
@@ -185,944 +1134,7086 @@ pub enum CameraBox {
         /// cases where it would be smaller.
         strict_windowboxing: bool,
     },
+
+    /// Keep the output at a static Aspect Ratio within the area left after removing fixed margins
+    /// from each side of the output, and center it within that area.
+    ///
+    /// This is useful for games with a permanent frame around the play area. If the margins don't
+    /// leave any room, then no boxing is done.
+    AspectRatioWithMargin {
+        #[cfg_attr(feature = "serde", serde(with = "aspect_ratio_serde"))]
+        aspect_ratio: AspectRatio,
+
+        /// The margins to remove, in pixels, in the order top, right, bottom, and left.
+        margin: UVec4,
+    },
+
+    /// Letterbox the output so that it is exactly `height` tall, and centered.
+    /// If the output is not taller than `height`, then no boxing is done.
+    FixedContentHeight { height: u32 },
+
+    /// Pillarbox the output so that it is exactly `width` wide, and centered.
+    /// If the output is not wider than `width`, then no boxing is done.
+    FixedContentWidth { width: u32 },
+
+    /// Keep the visible world exactly `world_height` units tall, while the visible width follows
+    /// the output, so the output is never pillarboxed.
+    ///
+    /// The camera's `OrthographicProjection` is kept at `ScalingMode::FixedVertical` with
+    /// `world_height` by the plugin, so the projection shouldn't be given a different scaling
+    /// mode. If the output is taller than it is wide, it's letterboxed to a centered square, so the
+    /// visible world is never narrower than it is tall.
+    ConstantVerticalExtent { world_height: f32 },
+
+    /// Box the output to the Aspect Ratio of a video with the size `video_size`.
+    /// This is the same as `StaticAspectRatio` with the video's Aspect Ratio, and is meant to be
+    /// used with `TemporaryBox::cutscene` while a prerendered cutscene is playing.
+    CutsceneLetterbox { video_size: UVec2 },
+
+    /// Split the output into `count` columns of the same width (one for each XR view), and box
+    /// `inner` within the column for `view_index`.
+    /// If `view_index` isn't less than `count`, then no boxing is done.
+    XrViews {
+        /// How many views the output is split into.
+        count: u32,
+
+        /// Which view this camera renders, starting from 0 on the left.
+        view_index: u32,
+
+        /// How to box the output within the view.
+        inner: Box<CameraBox>,
+    },
 }
 
-fn windows_changed(
-    mut boxing_event: MessageWriter<AdjustBoxing>,
-    window: Query<&Window, Changed<Window>>,
-) {
-    if !window.is_empty() {
-        boxing_event.write(AdjustBoxing);
+/// (De)serializes an `AspectRatio` as its ratio, since it doesn't implement serde itself.
+#[cfg(feature = "serde")]
+mod aspect_ratio_serde {
+    use bevy_math::AspectRatio;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        aspect_ratio: &AspectRatio,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        aspect_ratio.ratio().serialize(serializer)
     }
-}
 
-fn images_changed(mut boxing_event: MessageWriter<AdjustBoxing>) {
-    boxing_event.write(AdjustBoxing);
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<AspectRatio, D::Error> {
+        AspectRatio::try_new(f32::deserialize(deserializer)?, 1.).map_err(D::Error::custom)
+    }
 }
 
-fn texture_views_changed(mut boxing_event: MessageWriter<AdjustBoxing>) {
-    boxing_event.write(AdjustBoxing);
+/// Infallible constructors for common Aspect Ratios, so they don't need to go through
+/// `AspectRatio::try_new`.
+pub trait NamedAspectRatio {
+    /// 16:9, the Aspect Ratio of most monitors and TVs.
+    fn ratio_16_9() -> Self;
+
+    /// 4:3, the Aspect Ratio of older monitors and TVs.
+    fn ratio_4_3() -> Self;
+
+    /// 21:9, the Aspect Ratio of ultrawide monitors.
+    fn ratio_21_9() -> Self;
+
+    /// 1:1, a square.
+    fn ratio_1_1() -> Self;
 }
 
-fn camerabox_changed(
-    mut boxing_event: MessageWriter<AdjustBoxing>,
-    boxes: Query<&CameraBox, Changed<CameraBox>>,
-) {
-    if !boxes.is_empty() {
-        boxing_event.write(AdjustBoxing);
+impl NamedAspectRatio for AspectRatio {
+    fn ratio_16_9() -> Self {
+        AspectRatio::SIXTEEN_NINE
     }
-}
 
-enum ViewportChanges {
-    SetToNone,
-    Box(Boxing),
+    fn ratio_4_3() -> Self {
+        AspectRatio::FOUR_THREE
+    }
+
+    fn ratio_21_9() -> Self {
+        AspectRatio::ULTRAWIDE
+    }
+
+    fn ratio_1_1() -> Self {
+        AspectRatio::SQUARE
+    }
 }
 
-fn adjust_viewport(
-    mut boxed_cameras: Query<(
-        &mut Camera,
-        &mut RenderTarget,
-        &CameraBox,
-        Option<&HasNested>,
-    )>,
-    loose_boxes: Query<(&CameraBox, Option<&HasNested>), Without<Camera>>,
-    primary_window: Option<Single<Entity, With<PrimaryWindow>>>,
-    windows: Query<(Entity, &Window)>,
-    texture_views: Res<ManualTextureViews>,
-    images: Res<Assets<Image>>,
-) {
-    let primary_window = primary_window.map(|e| e.into_inner());
-    for (mut camera, target, camera_box, nested_box) in boxed_cameras.iter_mut() {
-        if !camera.is_active {
-            continue;
+impl CameraBox {
+    /// Creates a non-strict `CameraBox::LetterBox` with `top` and `bottom` bars.
+    ///
+    /// ```
+    /// # use bevy_simple_screen_boxing::CameraBox;
+    /// assert_eq!(
+    ///     CameraBox::letterbox(60, 60),
+    ///     CameraBox::LetterBox {
+    ///         top: 60,
+    ///         bottom: 60,
+    ///         strict_letterboxing: false,
+    ///         preserve_bar_ratio: false,
+    ///         logical_bars: false,
+    ///     }
+    /// );
+    /// ```
+    pub fn letterbox(top: u32, bottom: u32) -> Self {
+        Self::LetterBox {
+            top,
+            bottom,
+            strict_letterboxing: false,
+            preserve_bar_ratio: false,
+            logical_bars: false,
         }
+    }
 
-        let target = target.normalize(primary_window);
-        let target = match target
-            .map(|t| t.get_render_target_info(windows, &images, &texture_views))
-        {
-            None => {
-                info!(
-                    "Failed to get normalized render target! Are you rendering to a Primary Window without having set one?"
-                );
-                continue;
-            }
-            Some(Err(e)) => {
-                warn_once!("Missing Render Target Info: {:#?}", e);
-                continue;
-            }
-            Some(Ok(target)) => target,
-        };
+    /// Creates a non-strict `CameraBox::PillarBox` with `left` and `right` bars.
+    ///
+    /// ```
+    /// # use bevy_simple_screen_boxing::CameraBox;
+    /// assert_eq!(
+    ///     CameraBox::pillarbox(80, 80),
+    ///     CameraBox::PillarBox {
+    ///         left: 80,
+    ///         right: 80,
+    ///         strict_pillarboxing: false,
+    ///         logical_bars: false,
+    ///     }
+    /// );
+    /// ```
+    pub fn pillarbox(left: u32, right: u32) -> Self {
+        Self::PillarBox {
+            left,
+            right,
+            strict_pillarboxing: false,
+            logical_bars: false,
+        }
+    }
 
-        let mut viewport = match &mut camera.viewport {
-            None => Viewport::default(),
-            Some(viewport) => viewport.to_owned(),
-        };
+    /// Creates a non-strict `CameraBox::WindowBox` with `top`, `bottom`, `left`, and `right` bars.
+    ///
+    /// ```
+    /// # use bevy_simple_screen_boxing::CameraBox;
+    /// assert_eq!(
+    ///     CameraBox::windowbox(60, 60, 80, 80),
+    ///     CameraBox::WindowBox {
+    ///         top: 60,
+    ///         bottom: 60,
+    ///         left: 80,
+    ///         right: 80,
+    ///         strict_windowboxing: false,
+    ///     }
+    /// );
+    /// ```
+    pub fn windowbox(top: u32, bottom: u32, left: u32, right: u32) -> Self {
+        Self::WindowBox {
+            left,
+            right,
+            top,
+            bottom,
+            strict_windowboxing: false,
+        }
+    }
 
-        match calculate_changes(
-            camera_box,
-            &target.physical_size,
-            &UVec2::ZERO,
-            &viewport.physical_size,
-        ) {
-            None => continue,
-            Some(ViewportChanges::SetToNone) => {
-                let mut current_child = nested_box;
-                let mut actually_none = true;
-                let mut boxing_offset = Vec2::ZERO;
-                let mut output_resolution = target.physical_size.as_vec2();
-                while let Some(child) = current_child {
-                    let (actual_child, next) = match loose_boxes.get(child.0) {
-                        Err(_) => continue,
-                        Ok(c) => c,
-                    };
-
-                    match calculate_changes(
-                        actual_child,
-                        &output_resolution.as_uvec2(),
-                        &boxing_offset.as_uvec2(),
-                        &output_resolution.as_uvec2(),
-                    ) {
-                        None => break,
-                        Some(ViewportChanges::SetToNone) => (),
-                        Some(ViewportChanges::Box(boxing)) => {
-                            boxing_offset = boxing.boxing_offset;
-                            output_resolution = boxing.output_resolution;
-                            actually_none = false;
-                        }
-                    };
-                    current_child = next;
-                }
-                if actually_none {
-                    camera.viewport = None;
-                } else {
-                    viewport.physical_size = output_resolution.as_uvec2();
-                    viewport.physical_position = boxing_offset.as_uvec2();
-                    camera.viewport = Some(viewport);
-                }
-            }
-            Some(ViewportChanges::Box(Boxing {
-                mut boxing_offset,
-                mut output_resolution,
-            })) => {
-                let mut current_child = nested_box;
-                while let Some(child) = current_child {
-                    let (actual_child, next) = match loose_boxes.get(child.0) {
-                        Err(_) => continue,
-                        Ok(c) => c,
-                    };
-
-                    match calculate_changes(
-                        actual_child,
-                        &output_resolution.as_uvec2(),
-                        &boxing_offset.as_uvec2(),
-                        &output_resolution.as_uvec2(),
-                    ) {
-                        None => break,
-                        Some(ViewportChanges::SetToNone) => (),
-                        Some(ViewportChanges::Box(boxing)) => {
-                            boxing_offset = boxing.boxing_offset;
-                            output_resolution = boxing.output_resolution;
-                        }
-                    };
-                    current_child = next;
-                }
+    /// Creates a centered `CameraBox::StaticResolution` of `resolution`, which isn't scaled.
+    ///
+    /// ```
+    /// # use bevy_math::UVec2;
+    /// # use bevy_simple_screen_boxing::{CameraBox, ResolutionScaleMode};
+    /// assert_eq!(
+    ///     CameraBox::static_resolution(UVec2::new(640, 360)),
+    ///     CameraBox::StaticResolution {
+    ///         resolution: UVec2::new(640, 360),
+    ///         position: None,
+    ///         scale: ResolutionScaleMode::Exact,
+    ///     }
+    /// );
+    /// ```
+    pub fn static_resolution(resolution: UVec2) -> Self {
+        Self::StaticResolution {
+            resolution,
+            position: None,
+            scale: ResolutionScaleMode::Exact,
+        }
+    }
 
-                viewport.physical_size = output_resolution.as_uvec2();
-                viewport.physical_position = boxing_offset.as_uvec2();
-                camera.viewport = Some(viewport);
-            }
-        };
+    /// Creates a centered, non-strict, `CameraBox::StaticAspectRatio` of `aspect_ratio`.
+    ///
+    /// ```
+    /// # use bevy_math::AspectRatio;
+    /// # use bevy_simple_screen_boxing::CameraBox;
+    /// assert_eq!(
+    ///     CameraBox::static_aspect_ratio(AspectRatio::SIXTEEN_NINE),
+    ///     CameraBox::StaticAspectRatio {
+    ///         aspect_ratio: AspectRatio::SIXTEEN_NINE,
+    ///         position: None,
+    ///         strict: false,
+    ///     }
+    /// );
+    /// ```
+    pub fn static_aspect_ratio(aspect_ratio: AspectRatio) -> Self {
+        Self::StaticAspectRatio {
+            aspect_ratio,
+            position: None,
+            strict: false,
+        }
     }
-}
 
-fn calculate_changes(
-    camerabox: &CameraBox,
-    physical_resolution: &UVec2,
-    render_placement: &UVec2,
-    render_size: &UVec2,
-) -> Option<ViewportChanges> {
-    match &camerabox {
-        CameraBox::StaticResolution {
-            resolution,
-            position,
-        } => {
-            if physical_resolution == resolution && position.is_none() {
-                return Some(ViewportChanges::SetToNone);
-            } else if let Some(position) = position {
-                let offset = resolution.clamp(UVec2::ZERO, *physical_resolution) + position;
-                if (physical_resolution.x < offset.x || physical_resolution.y < offset.y)
-                    && render_placement == &UVec2::ZERO
-                {
-                    return None;
-                }
-            }
+    /// Creates a centered, non-strict, `CameraBox::StaticAspectRatio` of 16:9.
+    ///
+    /// ```
+    /// # use bevy_math::AspectRatio;
+    /// # use bevy_simple_screen_boxing::{CameraBox, NamedAspectRatio};
+    /// assert_eq!(
+    ///     CameraBox::aspect_16_9(),
+    ///     CameraBox::StaticAspectRatio {
+    ///         aspect_ratio: AspectRatio::ratio_16_9(),
+    ///         position: None,
+    ///         strict: false,
+    ///     }
+    /// );
+    /// assert_eq!(AspectRatio::ratio_16_9().ratio(), 16. / 9.);
+    /// ```
+    pub fn aspect_16_9() -> Self {
+        Self::static_aspect_ratio(AspectRatio::ratio_16_9())
+    }
 
-            let clamped_resolution = if render_size != resolution {
-                &resolution.clamp(UVec2::ONE, *physical_resolution)
-            } else {
-                resolution
-            };
+    /// Creates a centered, non-strict, `CameraBox::StaticAspectRatio` of 4:3.
+    pub fn aspect_4_3() -> Self {
+        Self::static_aspect_ratio(AspectRatio::ratio_4_3())
+    }
 
-            let render_placement = if position.is_none() {
-                (physical_resolution - resolution.clamp(UVec2::ZERO, *physical_resolution)) / 2
-            } else {
-                let position = position.unwrap();
-                let offset = resolution.clamp(UVec2::ZERO, *physical_resolution) + position;
-                if physical_resolution.x >= offset.x && physical_resolution.y >= offset.y {
-                    position
-                } else {
-                    warn_once!(
-                        "Unable to place output with resolution {} at position {} within Render Target with size {}. Placing at (0,0) instead",
-                        resolution,
-                        position,
-                        physical_resolution
-                    );
-                    UVec2::ZERO
-                }
-            } + render_placement;
-            Some(ViewportChanges::Box(Boxing {
-                boxing_offset: render_placement.as_vec2(),
-                output_resolution: clamped_resolution.as_vec2(),
-            }))
-        }
-        CameraBox::StaticAspectRatio {
-            aspect_ratio,
-            position,
-        } => {
-            let physical_aspect_ratio = match AspectRatio::try_from(physical_resolution.as_vec2()) {
-                Ok(ar) if ar.ratio() == aspect_ratio.ratio() => {
-                    return Some(ViewportChanges::SetToNone);
-                }
-                Err(e) => {
-                    warn!(
-                        "Error occurred when calculating aspect ratios for scaling: {:?}",
-                        e
-                    );
-                    return None;
-                }
-                Ok(ar) => ar,
-            };
+    /// Creates a centered, non-strict, `CameraBox::StaticAspectRatio` of 21:9.
+    pub fn aspect_21_9() -> Self {
+        Self::static_aspect_ratio(AspectRatio::ratio_21_9())
+    }
 
-            let Boxing {
-                boxing_offset,
-                output_resolution,
-            } = calculate_boxing_from_aspect_ratios(
-                &physical_resolution.as_vec2(),
-                &physical_aspect_ratio,
-                aspect_ratio,
-            );
+    /// Creates a centered, non-strict, `CameraBox::StaticAspectRatio` of 1:1.
+    pub fn aspect_1_1() -> Self {
+        Self::static_aspect_ratio(AspectRatio::ratio_1_1())
+    }
 
-            Some(ViewportChanges::Box(Boxing {
-                output_resolution,
-                boxing_offset: match position {
-                    None => boxing_offset + render_placement.as_vec2(),
-                    Some(pos) => {
-                        (if is_within_rect(physical_resolution, pos, render_size) {
-                            pos.as_vec2()
-                        } else {
-                            warn_once!(
-                                "Unable to place output with resolution {} at position {} within Render Target with size {}. Placing at (0,0) instead",
-                                output_resolution,
-                                pos,
-                                physical_resolution,
-                            );
-                            Vec2::ZERO
-                        }) + render_placement.as_vec2()
-                    }
-                },
-            }))
+    /// Makes the boxing strict, for the variants that have a strict option.
+    /// Any other variant is returned as it is.
+    ///
+    /// ```
+    /// # use bevy_simple_screen_boxing::CameraBox;
+    /// assert_eq!(
+    ///     CameraBox::letterbox(60, 60).strict(),
+    ///     CameraBox::LetterBox {
+    ///         top: 60,
+    ///         bottom: 60,
+    ///         strict_letterboxing: true,
+    ///         preserve_bar_ratio: false,
+    ///         logical_bars: false,
+    ///     }
+    /// );
+    /// ```
+    pub fn strict(mut self) -> Self {
+        match &mut self {
+            Self::StaticAspectRatio { strict, .. } => *strict = true,
+            Self::LetterBox {
+                strict_letterboxing,
+                ..
+            } => *strict_letterboxing = true,
+            Self::PillarBox {
+                strict_pillarboxing,
+                ..
+            } => *strict_pillarboxing = true,
+            Self::WindowBox {
+                strict_windowboxing,
+                ..
+            } => *strict_windowboxing = true,
+            _ => (),
         }
-        CameraBox::ResolutionIntegerScale {
-            resolution,
-            allow_imperfect_downscaled_boxing,
-        } => {
-            match if *allow_imperfect_downscaled_boxing {
-                calculate_boxing_imperfect(&physical_resolution.as_vec2(), resolution)
-            } else {
-                calculate_boxing_perfect(&physical_resolution.as_vec2(), resolution)
-            } {
-                Ok(None) => Some(ViewportChanges::SetToNone),
-                Ok(Some(Boxing {
-                    boxing_offset,
-                    output_resolution,
-                })) => Some(ViewportChanges::Box(Boxing {
-                    boxing_offset: render_placement.as_vec2() + boxing_offset,
-                    output_resolution,
-                })),
-                Err(e) => {
-                    warn!(
-                        "Error occurred when calculating aspect ratios for scaling: {:?}",
-                        e
-                    );
-                    None
-                }
+        self
+    }
+
+    /// Centers the boxed output, for the variants that can be positioned.
+    /// Any other variant is returned as it is.
+    ///
+    /// ```
+    /// # use bevy_math::UVec2;
+    /// # use bevy_simple_screen_boxing::{CameraBox, ResolutionScaleMode};
+    /// let camera_box = CameraBox::StaticResolution {
+    ///     resolution: UVec2::new(640, 360),
+    ///     position: Some(UVec2::new(10, 10)),
+    ///     scale: ResolutionScaleMode::Exact,
+    /// };
+    /// assert_eq!(
+    ///     camera_box.centered(),
+    ///     CameraBox::static_resolution(UVec2::new(640, 360))
+    /// );
+    /// ```
+    pub fn centered(mut self) -> Self {
+        match &mut self {
+            Self::StaticResolution { position, .. } | Self::StaticAspectRatio { position, .. } => {
+                *position = None;
             }
+            _ => (),
         }
-        CameraBox::LetterBox {
-            top,
-            bottom,
-            strict_letterboxing,
-        } => {
-            let Boxing {
-                mut boxing_offset,
-                mut output_resolution,
-            } = calculate_letterbox(&physical_resolution.as_vec2(), (top, bottom));
-            if (output_resolution.y + boxing_offset.y > physical_resolution.y as f32
-                || output_resolution.y <= 0.)
-                && !strict_letterboxing
-            {
-                output_resolution.y = physical_resolution.y as f32 / 2.;
-                boxing_offset.y /= 2.;
-                let scale_factor =
-                    (physical_resolution.y as f32) / (output_resolution.y + boxing_offset.y);
-                boxing_offset.y *= scale_factor;
-            }
+        self
+    }
 
-            if (output_resolution.y <= 0.
-                || output_resolution.y > physical_resolution.y as f32
-                || output_resolution.y + boxing_offset.y > physical_resolution.y as f32)
-                && *strict_letterboxing
-            {
-                return Some(ViewportChanges::SetToNone);
-            }
+    /// Returns how the boxed content maps into a render target with the size `target` (in physical
+    /// pixels), for compositing content that was rendered to its own texture.
+    ///
+    /// This is returned as `(scale, translation)`, where `scale` is the size of the content as a
+    /// fraction of the target, and `translation` is the offset from the center of the target to the
+    /// center of the content, in logical pixels (using `scale_factor`) with +Y being up, as in
+    /// Bevy's 2D world space. So a quad the size of the target with this scale and translation
+    /// covers exactly where the content would be boxed.
+    ///
+    /// If nothing would be boxed, then this returns `(Vec2::ONE, Vec2::ZERO)`.
+    pub fn composite_transform(&self, target: UVec2, scale_factor: f32) -> (Vec2, Vec2) {
+        let boxing =
+            match calculate_changes(self, &target, &UVec2::ZERO, &target, DEFAULT_ASPECT_EPSILON) {
+                Some(ViewportChanges::Box(boxing)) if target.cmpne(UVec2::ZERO).all() => boxing,
+                _ => return (Vec2::ONE, Vec2::ZERO),
+            };
 
-            Some(ViewportChanges::Box(Boxing {
-                boxing_offset: boxing_offset + render_placement.as_vec2(),
-                output_resolution,
-            }))
-        }
-        CameraBox::PillarBox {
-            left,
-            right,
-            strict_pillarboxing,
-        } => {
-            let Boxing {
-                mut boxing_offset,
-                mut output_resolution,
-            } = calculate_pillarbox(&physical_resolution.as_vec2(), (left, right));
+        let target = target.as_vec2();
+        let content_center = boxing.boxing_offset + boxing.output_resolution / 2.;
+        let translation = (content_center - target / 2.) / scale_factor;
+        (
+            boxing.output_resolution / target,
+            Vec2::new(translation.x, -translation.y),
+        )
+    }
 
-            if (output_resolution.x + boxing_offset.x > physical_resolution.x as f32
-                || output_resolution.x <= 0.)
-                && !strict_pillarboxing
-            {
-                output_resolution.x = physical_resolution.x as f32 / 2.;
-                boxing_offset.x /= 2.;
-                let scale_factor =
-                    (physical_resolution.x as f32) / (output_resolution.x + boxing_offset.x);
-                boxing_offset.x *= scale_factor;
+    /// Returns the Aspect Ratio the content is kept at within a render target with the size
+    /// `window_size` (in physical pixels), for showing the current mode to players.
+    ///
+    /// For the Aspect Ratio and resolution based variants this is the Aspect Ratio they're
+    /// configured with, and doesn't depend on `window_size`. For the variants that box with bars
+    /// (along with `ResolutionIntegerScale` when `independent_axis_scale` is true), this is the
+    /// Aspect Ratio of the content after boxing within `window_size`.
+    ///
+    /// This returns None if there is no valid Aspect Ratio, such as when a resolution is zero or
+    /// a strict variant can't be used within `window_size`.
+    pub fn target_aspect(&self, window_size: UVec2) -> Option<AspectRatio> {
+        let aspect_of = |size: UVec2| AspectRatio::try_from(size.as_vec2()).ok();
+        match self {
+            Self::StaticAspectRatio { aspect_ratio, .. }
+            | Self::AspectRatioWithMargin { aspect_ratio, .. } => Some(*aspect_ratio),
+            Self::StaticResolution { resolution, .. }
+            | Self::ResolutionIntegerScale {
+                resolution,
+                independent_axis_scale: false,
+                ..
+            } => aspect_of(*resolution),
+            Self::IntegerAspect { base } => aspect_of(*base),
+            Self::FixedTiles {
+                tile_size,
+                cols,
+                rows,
+            } => aspect_of(tile_size.saturating_mul(UVec2::new(*cols, *rows))),
+            Self::CutsceneLetterbox { video_size } => aspect_of(*video_size),
+            Self::XrViews { count, inner, .. } => {
+                inner.target_aspect(UVec2::new(window_size.x / count.max(&1), window_size.y))
             }
-
-            if output_resolution.x <= 0.
-                || output_resolution.x > physical_resolution.x as f32
-                || output_resolution.x + boxing_offset.x > physical_resolution.x as f32
-                    && *strict_pillarboxing
-            {
-                return Some(ViewportChanges::SetToNone);
+            Self::ResolutionIntegerScale { .. }
+            | Self::LetterBox { .. }
+            | Self::PillarBox { .. }
+            | Self::WindowBox { .. }
+            | Self::FixedContentHeight { .. }
+            | Self::FixedContentWidth { .. }
+            | Self::ConstantVerticalExtent { .. } => {
+                if window_size.cmpeq(UVec2::ZERO).any() {
+                    return None;
+                }
+                match calculate_changes(
+                    self,
+                    &window_size,
+                    &UVec2::ZERO,
+                    &window_size,
+                    DEFAULT_ASPECT_EPSILON,
+                )? {
+                    ViewportChanges::SetToNone => aspect_of(window_size),
+                    ViewportChanges::Box(boxing) => {
+                        AspectRatio::try_from(boxing.output_resolution).ok()
+                    }
+                    ViewportChanges::Degenerate | ViewportChanges::AspectMismatch { .. } => None,
+                }
             }
-
-            Some(ViewportChanges::Box(Boxing {
-                boxing_offset: boxing_offset + render_placement.as_vec2(),
-                output_resolution,
-            }))
         }
-        CameraBox::WindowBox {
-            left,
-            right,
-            top,
-            bottom,
-            strict_windowboxing,
-        } => {
-            let letterboxing = (top, bottom);
-            let pillarboxing = (left, right);
+    }
+}
 
-            let Boxing {
-                mut boxing_offset,
-                mut output_resolution,
-            } = calculate_windowbox(&physical_resolution.as_vec2(), [letterboxing, pillarboxing]);
+#[derive(Component, Reflect, Default, Clone, PartialEq, Debug)]
+#[reflect(Component)]
+/// The result of the most recent boxing calculation for a camera.
+///
+/// This is required by `CameraBox`, kept up to date by the plugin on every camera with a
+/// `CameraBox`, and should not be modified manually.
+///
+/// If a camera can't be boxed (e.g. its boxing is disabled with `CameraBoxEnabled`, or it's in
+/// exclusive fullscreen), this is reset to its default, which isn't boxed.
+pub struct ComputedBoxing {
+    /// The physical size of the render target the boxing was calculated against.
+    pub target_size: UVec2,
+
+    /// Where the boxed output is placed within the render target.
+    pub boxing_offset: Vec2,
+
+    /// The size of the boxed output.
+    pub output_resolution: Vec2,
+
+    /// The space left over in the render target once the output has been placed, per axis.
+    ///
+    /// This is the size of the render target minus the output resolution, and is what ends up
+    /// being split into the bars. If the camera has a `SubCameraView`, this is measured within the
+    /// part of the render target the sub view covers instead. For `CameraBox::ResolutionIntegerScale` this is the exact
+    /// remainder left by the integer scale, before any rounding to whole pixels.
+    pub remainder: Vec2,
+
+    /// If true, the camera's `CameraBox::ResolutionIntegerScale` couldn't be boxed to an Integer
+    /// Scale and fell back to imperfect downscaled boxing, because the render target is smaller
+    /// than the resolution and `allow_imperfect_downscaled_boxing` is true.
+    ///
+    /// This is useful for warning players that their display isn't ideal for the game.
+    pub used_imperfect_scaling: bool,
+}
 
-            if *strict_windowboxing {
-                if output_resolution.x <= 0.
-                    || !is_within_rect(
-                        physical_resolution,
-                        &boxing_offset.as_uvec2(),
-                        &output_resolution.as_uvec2(),
-                    )
-                {
-                    return Some(ViewportChanges::SetToNone);
-                }
-            } else {
-                if output_resolution.x + boxing_offset.x > physical_resolution.x as f32
-                    || output_resolution.x <= 0.
-                {
-                    output_resolution.x = physical_resolution.x as f32 / 2.;
-                    boxing_offset.x /= 2.;
-                    let scale_factor =
-                        (physical_resolution.x as f32) / (output_resolution.x + boxing_offset.x);
-                    boxing_offset.x *= scale_factor;
-                }
+impl ComputedBoxing {
+    /// Returns the part of a render target of size `target` that the boxed output covers, as UV
+    /// coordinates between 0 and 1.
+    ///
+    /// This is useful for post-processing that samples the whole render target, but should only
+    /// touch the boxed output and not the bars.
+    pub fn content_uv_rect(&self, target: UVec2) -> Rect {
+        let target = target.as_vec2();
+        Rect::from_corners(
+            self.boxing_offset / target,
+            (self.boxing_offset + self.output_resolution) / target,
+        )
+    }
 
-                if output_resolution.y + boxing_offset.y > physical_resolution.y as f32
-                    || output_resolution.y <= 0.
-                {
-                    output_resolution.y = physical_resolution.y as f32 / 2.;
-                    boxing_offset.y /= 2.;
-                    let scale_factor =
-                        (physical_resolution.y as f32) / (output_resolution.y + boxing_offset.y);
-                    boxing_offset.y *= scale_factor;
-                }
-            }
+    /// Returns true if the output doesn't cover the whole render target, so there are bars.
+    pub fn is_boxed(&self) -> bool {
+        self.remainder.cmpgt(Vec2::ZERO).any()
+    }
 
-            Some(ViewportChanges::Box(Boxing {
-                output_resolution,
-                boxing_offset: boxing_offset + render_placement.as_vec2(),
-            }))
+    /// Returns which axes of the output are smaller than the render target.
+    pub fn boxed_axes(&self) -> BoxedAxes {
+        match (self.remainder.x > 0., self.remainder.y > 0.) {
+            (false, false) => BoxedAxes::None,
+            (true, false) => BoxedAxes::Horizontal,
+            (false, true) => BoxedAxes::Vertical,
+            (true, true) => BoxedAxes::Both,
         }
     }
 }
 
-#[derive(PartialEq, Debug)]
-struct Boxing {
-    boxing_offset: Vec2,
-    output_resolution: Vec2,
+/// Which axes of the output are boxed, returned by `ComputedBoxing::boxed_axes`.
+///
+/// This is named after the axis that is shrunk, not the direction the bars run in. So
+/// letterboxing (bars at the top and bottom) is `Vertical`, while pillarboxing (bars on the left
+/// and right) is `Horizontal`.
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BoxedAxes {
+    /// The output covers the whole render target.
+    None,
+
+    /// The output is narrower than the render target, so there are bars on the left and right.
+    Horizontal,
+
+    /// The output is shorter than the render target, so there are bars at the top and bottom.
+    Vertical,
+
+    /// The output is both narrower and shorter than the render target.
+    Both,
 }
 
-fn calculate_boxing_from_aspect_ratios(
-    physical_size: &Vec2,
-    physical_aspect_ratio: &AspectRatio,
-    target_aspect_ratio: &AspectRatio,
-) -> Boxing {
-    if physical_aspect_ratio.ratio() > target_aspect_ratio.ratio() {
-        let render_height = physical_size.y;
-        let render_width = render_height * target_aspect_ratio.ratio();
-        Boxing {
-            boxing_offset: Vec2::new(physical_size.x / 2. - render_width / 2., 0.),
-            output_resolution: Vec2::new(render_width, render_height),
-        }
-    } else {
-        let render_width = physical_size.x;
-        let render_height = render_width / target_aspect_ratio.ratio();
-        Boxing {
-            boxing_offset: Vec2::new(0., physical_size.y / 2. - render_height / 2.),
-            output_resolution: Vec2::new(render_width, render_height),
-        }
+impl core::fmt::Display for ComputedBoxing {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let kind = match (self.remainder.x > 0., self.remainder.y > 0.) {
+            (false, false) => "Unboxed",
+            (false, true) => "Letterboxed",
+            (true, false) => "Pillarboxed",
+            (true, true) => "Windowboxed",
+        };
+        let far_bars = self.target_size.as_vec2() - self.boxing_offset - self.output_resolution;
+        write!(
+            f,
+            "{} {}x{} -> content {}x{} bars({},{},{},{})",
+            kind,
+            self.target_size.x,
+            self.target_size.y,
+            self.output_resolution.x,
+            self.output_resolution.y,
+            self.boxing_offset.x,
+            far_bars.x,
+            self.boxing_offset.y,
+            far_bars.y,
+        )
     }
 }
-fn calculate_boxing_imperfect(physical_size: &Vec2, desired_size: &Vec2) -> Result<Option<Boxing>> {
-    let desired_aspect_ratio = AspectRatio::try_from(*desired_size)?;
-    let physical_aspect_ratio = AspectRatio::try_from(*physical_size)?;
 
-    //NOTE: this does not really handle the case where the target size is smaller than the desired height/width.
-    let height_scale = physical_size.y / desired_size.y;
-    let width_scale = physical_size.x / desired_size.x;
+/// This event is sent when a camera goes from being boxed to unboxed, or from unboxed to boxed.
+///
+/// Unlike `ComputedBoxing` changing, this is not sent when only the size of the bars change.
+#[derive(Message, Clone, Debug)]
+pub struct BoxingToggled {
+    pub camera: Entity,
+    pub now_boxing: bool,
+}
 
-    let small_height_scale = desired_size.y / physical_size.y;
-    let small_width_scale = desired_size.x / physical_size.x;
+/// A Message that is sent whenever the viewport of a boxed camera is changed by the boxing.
+///
+/// This is only sent when the viewport actually changes, so it can be used to reposition things
+/// (like a HUD) that depend on where the output is, without checking the viewport every frame.
+#[derive(Message, Clone, Debug)]
+pub struct CameraBoxed {
+    pub camera: Entity,
+
+    /// The camera's new viewport, this is None if the viewport was cleared.
+    pub viewport: Option<Viewport>,
+
+    /// The offset of the boxed output from the top-left corner of the render target.
+    pub boxing_offset: Vec2,
+
+    /// The size of the boxed output.
+    ///
+    /// If the viewport was cleared without the boxing being calculated (e.g. because the camera's
+    /// boxing was disabled), this and `boxing_offset` are zero.
+    pub output_resolution: Vec2,
+}
 
-    let has_int_scale = desired_aspect_ratio.ratio() == physical_aspect_ratio.ratio()
-        && ((height_scale % 1. == 0. && width_scale % 1. == 0.)
-            || (small_height_scale % 1. == 0. && small_width_scale % 1. == 0.));
+/// A snapshot of how every boxed camera is configured, and how it is currently boxed.
+///
+/// This is meant to be dumped into bug reports. The plugin only keeps it up to date if the
+/// resource exists, so insert it to opt in.
+#[derive(Resource, Reflect, Default, Clone, Debug)]
+#[reflect(Resource)]
+pub struct BoxingDebugSnapshot(pub Vec<CameraBoxDebugEntry>);
+
+/// A single camera in a `BoxingDebugSnapshot`.
+#[derive(Reflect, Clone, Debug)]
+pub struct CameraBoxDebugEntry {
+    /// The boxed camera.
+    pub camera: Entity,
+
+    /// How the camera is configured to be boxed.
+    pub camera_box: CameraBox,
+
+    /// The size of the render target, as of the last time the boxing was calculated.
+    /// This is None if the boxing has not been calculated yet.
+    pub target_size: Option<UVec2>,
+
+    /// The viewport currently applied to the camera.
+    pub viewport: Option<Viewport>,
+}
 
-    // Integer Scaling Exists
-    if has_int_scale {
-        return Ok(None);
+/// Everything that configures how a camera is boxed, captured with `capture_boxing_state` so that
+/// it can be restored later with `restore_boxing_state`.
+///
+/// Each field is None if the camera didn't have that component.
+#[derive(Reflect, Default, Clone, PartialEq, Debug)]
+pub struct BoxingState {
+    pub camera_box: Option<CameraBox>,
+    pub enabled: Option<CameraBoxEnabled>,
+    pub anchor: Option<BoxAnchor>,
+    pub anchored_offset: Option<AnchoredOffset>,
+    pub bars: Option<CameraBoxBars>,
+    pub shake: Option<ViewportShake>,
+    pub aspect_tolerance: Option<AspectTolerance>,
+    pub min_viewport_size: Option<MinViewportSize>,
+    pub group: Option<CameraBoxGroup>,
+    pub inactive_box: Option<InactiveBox>,
+    pub pending_target_size: Option<PendingTargetSize>,
+    pub original_viewport: Option<OriginalViewport>,
+    pub scale_factor_override: Option<ScaleFactorOverride>,
+}
+
+/// Captures the boxing configuration of `camera`.
+///
+/// Temporary effects (like `IrisReveal` and `TemporaryBox`) are not captured.
+pub fn capture_boxing_state(world: &World, camera: Entity) -> BoxingState {
+    BoxingState {
+        camera_box: world.get::<CameraBox>(camera).cloned(),
+        enabled: world.get::<CameraBoxEnabled>(camera).copied(),
+        anchor: world.get::<BoxAnchor>(camera).copied(),
+        anchored_offset: world.get::<AnchoredOffset>(camera).copied(),
+        bars: world.get::<CameraBoxBars>(camera).copied(),
+        shake: world.get::<ViewportShake>(camera).copied(),
+        aspect_tolerance: world.get::<AspectTolerance>(camera).copied(),
+        min_viewport_size: world.get::<MinViewportSize>(camera).copied(),
+        group: world.get::<CameraBoxGroup>(camera).copied(),
+        inactive_box: world.get::<InactiveBox>(camera).cloned(),
+        pending_target_size: world.get::<PendingTargetSize>(camera).cloned(),
+        original_viewport: world.get::<OriginalViewport>(camera).cloned(),
+        scale_factor_override: world.get::<ScaleFactorOverride>(camera).copied(),
     }
+}
 
-    let best_scale = if width_scale > height_scale {
-        height_scale
-    } else {
-        width_scale
+/// Restores the boxing configuration of `camera` from `state`, then sends `AdjustBoxing`.
+///
+/// Components that are None in `state` are removed from the camera. This does nothing if `camera`
+/// doesn't exist.
+pub fn restore_boxing_state(world: &mut World, camera: Entity, state: BoxingState) {
+    let Ok(mut entity) = world.get_entity_mut(camera) else {
+        return;
     };
 
-    let render_width = if best_scale >= 1. {
-        desired_size.x * best_scale.floor()
-    } else {
-        desired_size.x * best_scale
-    };
+    fn restore<T: Component>(entity: &mut EntityWorldMut, component: Option<T>) {
+        match component {
+            Some(component) => {
+                entity.insert(component);
+            }
+            None => {
+                entity.remove::<T>();
+            }
+        }
+    }
+    restore(&mut entity, state.camera_box);
+    restore(&mut entity, state.enabled);
+    restore(&mut entity, state.anchor);
+    restore(&mut entity, state.anchored_offset);
+    restore(&mut entity, state.bars);
+    restore(&mut entity, state.shake);
+    restore(&mut entity, state.aspect_tolerance);
+    restore(&mut entity, state.min_viewport_size);
+    restore(&mut entity, state.group);
+    restore(&mut entity, state.inactive_box);
+    restore(&mut entity, state.pending_target_size);
+    restore(&mut entity, state.original_viewport);
+    restore(&mut entity, state.scale_factor_override);
+
+    if world.contains_resource::<Messages<AdjustBoxing>>() {
+        world.write_message(AdjustBoxing);
+    }
+}
 
-    let render_height = if best_scale >= 1. {
-        desired_size.y * best_scale.floor()
-    } else {
-        desired_size.y * best_scale
+fn spawn_box_bars(
+    mut commands: Commands,
+    cameras: Query<(Entity, &Camera, &RenderTarget, &CameraBoxBars), Added<CameraBoxBars>>,
+) {
+    // The order is only a starting point, `sync_box_bars` moves it below the other cameras.
+    for (entity, camera, render_target, bars) in cameras.iter() {
+        commands.spawn((
+            Camera2d,
+            Camera {
+                order: camera.order - 1,
+                is_active: camera.is_active,
+                clear_color: ClearColorConfig::Custom(bars.color),
+                ..Camera::default()
+            },
+            render_target.clone(),
+            RenderLayers::none(),
+            BoxBarsFor { camera: entity },
+        ));
+    }
+}
+
+fn sync_box_bars(
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &RenderTarget, Option<&CameraBoxBars>), Without<BoxBarsFor>>,
+    mut bar_cameras: Query<(Entity, &BoxBarsFor, &mut Camera, &mut RenderTarget)>,
+) {
+    let primary_window = primary_window.single().ok();
+
+    // Every bar camera is placed below the lowest camera on its render target, and below the bar
+    // cameras placed before it, so they're never ambiguous with each other. They're placed in order
+    // of the camera they're for, so the orders don't depend on the query's iteration order.
+    let mut bars = bar_cameras
+        .iter()
+        .map(|(entity, bars_for, ..)| (bars_for.camera, entity))
+        .collect::<Vec<_>>();
+    bars.sort();
+    let mut next_orders: Vec<(Option<NormalizedRenderTarget>, isize)> = Vec::new();
+    for (boxed_entity, bar_entity) in bars {
+        let Ok((boxed_camera, boxed_target, Some(bars))) = cameras.get(boxed_entity) else {
+            continue;
+        };
+        let target = boxed_target.normalize(primary_window);
+        // Bar cameras with a configured order are left out of the automatic ordering.
+        let order = match bars.order {
+            Some(order) => order,
+            None => match next_orders.iter_mut().find(|(other, _)| *other == target) {
+                Some((_, next_order)) => {
+                    *next_order -= 1;
+                    *next_order
+                }
+                None => {
+                    let lowest = cameras
+                        .iter()
+                        .filter(|(_, other, _)| other.normalize(primary_window) == target)
+                        .map(|(camera, ..)| camera.order)
+                        .min()
+                        .unwrap_or(boxed_camera.order);
+                    next_orders.push((target, lowest - 1));
+                    lowest - 1
+                }
+            },
+        };
+
+        let Ok((_, _, mut camera, mut render_target)) = bar_cameras.get_mut(bar_entity) else {
+            continue;
+        };
+        if camera.order != order {
+            camera.order = order;
+        }
+        if camera.is_active != boxed_camera.is_active {
+            camera.is_active = boxed_camera.is_active;
+        }
+        if !matches!(camera.clear_color, ClearColorConfig::Custom(color) if color == bars.color) {
+            camera.clear_color = ClearColorConfig::Custom(bars.color);
+        }
+        render_target.set_if_neq(boxed_target.clone());
+    }
+}
+
+/// Keeps the projection of every camera with a `CameraBox::ConstantVerticalExtent` showing its
+/// world height.
+fn sync_vertical_extents(
+    mut cameras: Query<
+        (Entity, &CameraBox, &mut Projection),
+        Or<(Changed<CameraBox>, Changed<Projection>)>,
+    >,
+) {
+    for (entity, camera_box, mut projection) in cameras.iter_mut() {
+        let CameraBox::ConstantVerticalExtent { world_height } = camera_box else {
+            continue;
+        };
+        let Projection::Orthographic(orthographic) = &*projection else {
+            warn_once!(
+                "Camera {} uses CameraBox::ConstantVerticalExtent without an orthographic projection, its world height won't be kept",
+                entity,
+            );
+            continue;
+        };
+        if matches!(
+            orthographic.scaling_mode,
+            ScalingMode::FixedVertical { viewport_height } if viewport_height == *world_height
+        ) {
+            continue;
+        }
+
+        if let Projection::Orthographic(orthographic) = &mut *projection {
+            orthographic.scaling_mode = ScalingMode::FixedVertical {
+                viewport_height: *world_height,
+            };
+        }
+    }
+}
+
+fn remove_box_bars(
+    mut commands: Commands,
+    mut removed_bars: RemovedComponents<CameraBoxBars>,
+    bar_cameras: Query<(Entity, &BoxBarsFor)>,
+) {
+    for entity in removed_bars.read() {
+        for (bar_camera, bars_for) in bar_cameras.iter() {
+            if bars_for.camera == entity {
+                commands.entity(bar_camera).try_despawn();
+            }
+        }
+    }
+}
+
+fn apply_viewport_shake(
+    mut shaking_cameras: Query<(&mut Camera, &ComputedBoxing, &ViewportShake)>,
+    mut still_cameras: Query<(&mut Camera, &ComputedBoxing), Without<ViewportShake>>,
+    mut removed_shakes: RemovedComponents<ViewportShake>,
+) {
+    // Cameras that stopped shaking are put back where their boxing placed them.
+    for entity in removed_shakes.read() {
+        if let Ok((mut camera, computed)) = still_cameras.get_mut(entity) {
+            move_viewport(&mut camera, computed.boxing_offset.as_uvec2());
+        }
+    }
+
+    for (mut camera, computed, shake) in shaking_cameras.iter_mut() {
+        let max_offset =
+            (computed.target_size.as_vec2() - computed.output_resolution).max(Vec2::ZERO);
+        let position = (computed.boxing_offset + shake.offset.as_vec2())
+            .clamp(Vec2::ZERO, max_offset)
+            .as_uvec2();
+        move_viewport(&mut camera, position);
+    }
+}
+
+/// Moves the camera's viewport, if it has one, without triggering change detection if it's already
+/// in the right place.
+fn move_viewport(camera: &mut Mut<Camera>, position: UVec2) {
+    if camera
+        .viewport
+        .as_ref()
+        .is_some_and(|viewport| viewport.physical_position != position)
+        && let Some(viewport) = &mut camera.viewport
+    {
+        viewport.physical_position = position;
+    }
+}
+
+fn update_iris_reveals(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut iris_reveals: Query<(Entity, &mut IrisReveal)>,
+    mut boxing_event: MessageWriter<AdjustBoxingFor>,
+) {
+    for (entity, mut iris_reveal) in iris_reveals.iter_mut() {
+        iris_reveal.elapsed += time.delta();
+        if iris_reveal.elapsed >= iris_reveal.duration {
+            commands.entity(entity).try_remove::<IrisReveal>();
+        }
+        boxing_event.write(AdjustBoxingFor(entity));
+    }
+}
+
+fn update_aspect_ratio_tweens(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut tweens: Query<(Entity, &mut AspectRatioTween, &mut CameraBox)>,
+) {
+    for (entity, mut tween, mut camera_box) in tweens.iter_mut() {
+        tween.timer.tick(time.delta());
+        let (position, strict) = match *camera_box {
+            CameraBox::StaticAspectRatio {
+                position, strict, ..
+            } => (position, strict),
+            _ => (None, false),
+        };
+        camera_box.set_if_neq(CameraBox::StaticAspectRatio {
+            aspect_ratio: tween.current(),
+            position,
+            strict,
+        });
+        if tween.timer.is_finished() {
+            commands.entity(entity).try_remove::<AspectRatioTween>();
+        }
+    }
+}
+
+fn update_camera_box_transitions(
+    time: Res<Time>,
+    mut transitions: Query<(Entity, &mut CameraBoxTransition)>,
+    mut boxing_event: MessageWriter<AdjustBoxingFor>,
+) {
+    for (entity, mut transition) in transitions.iter_mut() {
+        if transition.is_finished() {
+            continue;
+        }
+        transition.elapsed += time.delta();
+        boxing_event.write(AdjustBoxingFor(entity));
+    }
+}
+
+fn update_temporary_boxes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut temporary_boxes: Query<(
+        Entity,
+        &mut TemporaryBox,
+        Option<&mut CameraBox>,
+        Option<&mut Camera>,
+    )>,
+) {
+    for (entity, mut temporary_box, camera_box, camera) in temporary_boxes.iter_mut() {
+        if temporary_box.is_added() {
+            let mode = temporary_box.mode.clone();
+            temporary_box.previous = match camera_box {
+                Some(mut camera_box) => Some(core::mem::replace(&mut *camera_box, mode)),
+                None => {
+                    commands.entity(entity).try_insert(mode);
+                    None
+                }
+            };
+            continue;
+        }
+
+        if !temporary_box.timer.tick(time.delta()).is_finished() {
+            continue;
+        }
+
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.try_remove::<TemporaryBox>();
+        match (temporary_box.previous.take(), camera_box) {
+            (Some(previous), Some(mut camera_box)) => *camera_box = previous,
+            (Some(previous), None) => {
+                entity_commands.try_insert(previous);
+            }
+            (None, _) => {
+                entity_commands.try_remove::<CameraBox>();
+                if let Some(mut camera) = camera {
+                    camera.viewport = None;
+                }
+            }
+        }
+    }
+}
+
+fn update_debug_snapshot(
+    mut snapshot: ResMut<BoxingDebugSnapshot>,
+    cameras: Query<(Entity, &Camera, &CameraBox, Option<&ComputedBoxing>)>,
+) {
+    snapshot.0 = cameras
+        .iter()
+        .map(
+            |(entity, camera, camera_box, computed)| CameraBoxDebugEntry {
+                camera: entity,
+                camera_box: camera_box.clone(),
+                target_size: computed.map(|computed| computed.target_size),
+                viewport: camera.viewport.clone(),
+            },
+        )
+        .collect();
+}
+
+#[cfg(feature = "debug_gizmos")]
+fn draw_boxing_debug(
+    mut gizmos: Gizmos,
+    cameras: Query<(&Camera, &GlobalTransform, &ComputedBoxing, &CameraBoxDebug)>,
+) {
+    for (camera, transform, computed, debug) in cameras.iter() {
+        let scale_factor = camera.target_scaling_factor().unwrap_or(1.);
+        // Points are given in physical pixels relative to the render target, which have to be
+        // made relative to the (logical) viewport before they can be put into world space.
+        let to_world = |point: Vec2| {
+            camera
+                .viewport_to_world_2d(transform, (point - computed.boxing_offset) / scale_factor)
+                .ok()
+        };
+
+        let target = computed.target_size.as_vec2();
+        let content_min = computed.boxing_offset;
+        let content_max = computed.boxing_offset + computed.output_resolution;
+        let bars = [
+            (Vec2::ZERO, Vec2::new(target.x, content_min.y)),
+            (Vec2::new(0., content_max.y), target),
+            (
+                Vec2::new(0., content_min.y),
+                Vec2::new(content_min.x, content_max.y),
+            ),
+            (
+                Vec2::new(content_max.x, content_min.y),
+                Vec2::new(target.x, content_max.y),
+            ),
+        ];
+
+        if let (Some(min), Some(max)) = (to_world(content_min), to_world(content_max)) {
+            gizmos.rect_2d((min + max) / 2., (max - min).abs(), debug.color);
+        }
+        for (min, max) in bars {
+            if (max - min).cmple(Vec2::ZERO).any() {
+                continue;
+            }
+            let (Some(min), Some(max)) = (to_world(min), to_world(max)) else {
+                continue;
+            };
+            gizmos.rect_2d((min + max) / 2., (max - min).abs(), debug.color);
+            gizmos.line_2d(min, max, debug.color);
+            gizmos.line_2d(
+                Vec2::new(min.x, max.y),
+                Vec2::new(max.x, min.y),
+                debug.color,
+            );
+        }
+    }
+}
+
+fn log_boxing_changes(boxes: Query<(Entity, &ComputedBoxing), Changed<ComputedBoxing>>) {
+    for (entity, computed) in boxes.iter() {
+        info!("Camera {}: {}", entity, computed);
+    }
+}
+
+fn windows_changed(
+    mut boxing_event: MessageWriter<AdjustBoxing>,
+    settings: Option<Res<CameraBoxingSettings>>,
+    windows: Query<(Entity, &Window), Changed<Window>>,
+    mut locked_sizes: Local<EntityHashMap<(UVec2, f32, WindowMode)>>,
+    mut boxed_sizes: Local<EntityHashMap<(UVec2, f32, WindowMode)>>,
+) {
+    let stability_threshold = settings.map_or(0, |settings| settings.stability_threshold);
+
+    // Windows that can't be resized are changed for many reasons that don't affect the boxing
+    // (like the cursor moving), so for those we only recalculate when something the boxing reads
+    // from the window changes: its size, its scale (including any override), or its mode.
+    let mut changed = false;
+    for (entity, window) in windows.iter() {
+        let size = (
+            window.resolution.physical_size(),
+            window.resolution.scale_factor(),
+            window.mode,
+        );
+
+        if stability_threshold > 0 {
+            let jittered =
+                boxed_sizes
+                    .get(&entity)
+                    .is_some_and(|&(boxed_size, scale_factor, mode)| {
+                        let difference = boxed_size.max(size.0) - boxed_size.min(size.0);
+                        scale_factor == size.1
+                            && mode == size.2
+                            && difference != UVec2::ZERO
+                            && difference.max_element() < stability_threshold
+                    });
+            if jittered {
+                continue;
+            }
+            boxed_sizes.insert(entity, size);
+        }
+
+        if window.resizable {
+            locked_sizes.remove(&entity);
+            changed = true;
+            continue;
+        }
+
+        if locked_sizes.insert(entity, size) != Some(size) {
+            changed = true;
+        }
+    }
+
+    if changed {
+        boxing_event.write(AdjustBoxing);
+    }
+}
+
+/// Checks if the window is in exclusive fullscreen, without changing the display's resolution.
+fn is_exclusive_fullscreen_at_native(window: &Window) -> bool {
+    match window.mode {
+        WindowMode::Fullscreen(_, VideoModeSelection::Current) => true,
+        WindowMode::Fullscreen(_, VideoModeSelection::Specific(mode)) => {
+            mode.physical_size == window.resolution.physical_size()
+        }
+        _ => false,
+    }
+}
+
+/// Replaces `sizes` with the `current` sizes of the render targets of boxed cameras, returning
+/// true if any of them changed (or a render target was added or removed).
+fn target_sizes_changed<T: PartialEq>(
+    sizes: &mut Vec<(T, Option<UVec2>)>,
+    current: impl Iterator<Item = (T, Option<UVec2>)>,
+) -> bool {
+    let current = current.collect::<Vec<_>>();
+    let changed = *sizes != current;
+    *sizes = current;
+    changed
+}
+
+// Images and texture views change for lots of reasons that have nothing to do with boxing (like
+// any asset being loaded), so we only recalculate when the size of one that a boxed camera
+// renders to changes.
+fn images_changed(
+    mut boxing_event: MessageWriter<AdjustBoxing>,
+    images: Option<Res<Assets<Image>>>,
+    targets: Query<&RenderTarget, With<CameraBox>>,
+    mut target_sizes: Local<Vec<(AssetId<Image>, Option<UVec2>)>>,
+) {
+    let current = targets.iter().filter_map(|target| match target {
+        RenderTarget::Image(image) => {
+            let id = image.handle.id();
+            let size = images
+                .as_ref()
+                .and_then(|images| images.get(id))
+                .map(Image::size);
+            Some((id, size))
+        }
+        _ => None,
+    });
+    if target_sizes_changed(&mut target_sizes, current) {
+        boxing_event.write(AdjustBoxing);
+    }
+}
+
+fn texture_views_changed(
+    mut boxing_event: MessageWriter<AdjustBoxing>,
+    texture_views: Option<Res<ManualTextureViews>>,
+    targets: Query<&RenderTarget, With<CameraBox>>,
+    mut target_sizes: Local<Vec<(ManualTextureViewHandle, Option<UVec2>)>>,
+) {
+    let current = targets.iter().filter_map(|target| match target {
+        RenderTarget::TextureView(handle) => {
+            let size = texture_views
+                .as_ref()
+                .and_then(|texture_views| texture_views.get(handle))
+                .map(|texture_view| texture_view.size);
+            Some((*handle, size))
+        }
+        _ => None,
+    });
+    if target_sizes_changed(&mut target_sizes, current) {
+        boxing_event.write(AdjustBoxing);
+    }
+}
+
+fn insert_default_camera_box(
+    mut commands: Commands,
+    settings: Res<CameraBoxingSettings>,
+    new_cameras: Query<Entity, (Added<Camera>, Without<CameraBox>, Without<BoxBarsFor>)>,
+) {
+    let Some(default_camera_box) = &settings.default_camera_box else {
+        return;
     };
+    for entity in new_cameras.iter() {
+        commands
+            .entity(entity)
+            .try_insert(default_camera_box.clone());
+    }
+}
 
-    let letterbox_size = physical_size.y - render_height;
-    let pillarbox_size = physical_size.x - render_width;
+fn store_original_viewports(
+    mut commands: Commands,
+    new_boxes: Query<(Entity, &Camera), (Added<CameraBox>, Without<OriginalViewport>)>,
+) {
+    for (entity, camera) in new_boxes.iter() {
+        commands
+            .entity(entity)
+            .try_insert(OriginalViewport(camera.viewport.clone()));
+    }
+}
 
-    Ok(Some(Boxing {
-        boxing_offset: Vec2::new(pillarbox_size / 2., letterbox_size / 2.),
-        output_resolution: Vec2::new(render_width, render_height),
-    }))
+fn restore_original_viewports(
+    mut commands: Commands,
+    mut removed_boxes: RemovedComponents<CameraBox>,
+    mut cameras: Query<(&mut Camera, &OriginalViewport), Without<CameraBox>>,
+) {
+    for entity in removed_boxes.read() {
+        let Ok((mut camera, original_viewport)) = cameras.get_mut(entity) else {
+            continue;
+        };
+        camera.viewport = original_viewport.0.clone();
+        commands.entity(entity).try_remove::<OriginalViewport>();
+    }
 }
-fn calculate_boxing_perfect(physical_size: &Vec2, desired_size: &Vec2) -> Result<Option<Boxing>> {
-    let desired_aspect_ratio = AspectRatio::try_from(*desired_size)?;
-    let physical_aspect_ratio = AspectRatio::try_from(*physical_size)?;
 
-    let height_scale = physical_size.y / desired_size.y;
-    let width_scale = physical_size.x / desired_size.x;
+fn reset_boxing_changed(mut boxing_changed: ResMut<BoxingChangedThisFrame>) {
+    boxing_changed.set_if_neq(BoxingChangedThisFrame(false));
+}
 
-    let has_int_scale = desired_aspect_ratio.ratio() == physical_aspect_ratio.ratio()
-        && (height_scale % 1. == 0. && width_scale % 1. == 0.);
+fn settings_changed(mut boxing_event: MessageWriter<AdjustBoxing>) {
+    boxing_event.write(AdjustBoxing);
+}
 
-    // Integer Scaling Exists
-    if has_int_scale {
-        return Ok(None);
+/// Changes to any of the per-camera components that configure how a camera is boxed.
+/// Any new configuration component needs to be added here (and to `camerabox_changed` if removing
+/// it changes the boxing), otherwise changing it won't take effect until something else changes.
+type BoxingConfigChanged = Or<(
+    Changed<CameraBox>,
+    Changed<PendingTargetSize>,
+    Changed<BoxAnchor>,
+    Changed<AnchoredOffset>,
+    Changed<CameraBoxEnabled>,
+    Changed<MinViewportSize>,
+    Changed<HasNested>,
+    Changed<CameraBoxGroup>,
+    Changed<AspectTolerance>,
+    Changed<InactiveBox>,
+    Changed<ScaleFactorOverride>,
+)>;
+
+fn camerabox_changed(
+    mut boxing_event: MessageWriter<AdjustBoxing>,
+    boxes: Query<(), BoxingConfigChanged>,
+    mut removed_anchors: RemovedComponents<BoxAnchor>,
+    mut removed_offsets: RemovedComponents<AnchoredOffset>,
+    mut removed_enabled: RemovedComponents<CameraBoxEnabled>,
+    mut removed_min_sizes: RemovedComponents<MinViewportSize>,
+    mut removed_groups: RemovedComponents<CameraBoxGroup>,
+    mut removed_tolerances: RemovedComponents<AspectTolerance>,
+    mut removed_inactive_boxes: RemovedComponents<InactiveBox>,
+    mut removed_scale_factors: RemovedComponents<ScaleFactorOverride>,
+) {
+    let removed = removed_anchors.read().count()
+        + removed_offsets.read().count()
+        + removed_enabled.read().count()
+        + removed_min_sizes.read().count()
+        + removed_groups.read().count()
+        + removed_tolerances.read().count()
+        + removed_inactive_boxes.read().count()
+        + removed_scale_factors.read().count();
+    if !boxes.is_empty() || removed > 0 {
+        boxing_event.write(AdjustBoxing);
     }
+}
 
-    if height_scale < 1. || width_scale < 1. {
-        let height_scale = desired_size.y / physical_size.y;
-        let width_scale = desired_size.x / physical_size.x;
+// Cameras are changed every time they're boxed, so rather than recalculating on any change, we
+// only recalculate when a camera with an `InactiveBox` is activated or deactivated.
+fn activity_changed(
+    mut boxing_event: MessageWriter<AdjustBoxing>,
+    cameras: Query<(Entity, &Camera), (With<CameraBox>, With<InactiveBox>)>,
+    mut was_active: Local<EntityHashMap<bool>>,
+) {
+    let mut changed = false;
+    for (entity, camera) in cameras.iter() {
+        changed |= was_active.insert(entity, camera.is_active) != Some(camera.is_active);
+    }
+    was_active.retain(|entity, _| cameras.contains(*entity));
+
+    if changed {
+        boxing_event.write(AdjustBoxing);
+    }
+}
+
+enum ViewportChanges {
+    SetToNone,
+    /// The render target (or the `CameraBox`) is degenerate and can't be boxed, so the viewport is
+    /// cleared even if `CameraBoxingSettings::on_no_box` would leave it.
+    Degenerate,
+    Box(Boxing),
+    AspectMismatch {
+        expected: AspectRatio,
+        actual: AspectRatio,
+    },
+}
+
+#[derive(SystemParam)]
+/// Everything needed to calculate and apply the boxing for cameras.
+struct ViewportBoxing<'w, 's> {
+    commands: Commands<'w, 's>,
+    boxed_cameras: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static mut Camera,
+            &'static RenderTarget,
+            &'static CameraBox,
+            Option<&'static HasNested>,
+            Option<&'static mut ComputedBoxing>,
+            Option<&'static mut PendingTargetSize>,
+            Option<&'static BoxAnchor>,
+            Option<&'static AnchoredOffset>,
+            Option<&'static IrisReveal>,
+            Option<&'static CameraBoxEnabled>,
+            Option<&'static MinViewportSize>,
+            Option<&'static mut CameraBoxTransition>,
+            Option<&'static CameraBoxGroup>,
+            (
+                Option<&'static AspectTolerance>,
+                Option<&'static ScaleFactorOverride>,
+            ),
+            Option<&'static InactiveBox>,
+        ),
+    >,
+    loose_boxes: Query<'w, 's, (&'static CameraBox, Option<&'static HasNested>), Without<Camera>>,
+    primary_window: Query<'w, 's, Entity, With<PrimaryWindow>>,
+    windows: Query<'w, 's, (Entity, &'static Window)>,
+    texture_views: Res<'w, ManualTextureViews>,
+    images: Res<'w, Assets<Image>>,
+    settings: Res<'w, CameraBoxingSettings>,
+    layout_editing: Option<Res<'w, LayoutEditing>>,
+    boxing_changed: Option<ResMut<'w, BoxingChangedThisFrame>>,
+    errors: MessageWriter<'w, BoxingError>,
+    toggled: MessageWriter<'w, BoxingToggled>,
+    camera_boxed: MessageWriter<'w, CameraBoxed>,
+}
+
+impl ViewportBoxing<'_, '_> {
+    /// Recalculates the boxing of every boxed camera, or only the cameras in `only` if it is set.
+    fn adjust(&mut self, only: Option<&[Entity]>) {
+        if self
+            .layout_editing
+            .as_ref()
+            .is_some_and(|layout_editing| layout_editing.0)
+        {
+            return;
+        }
+
+        let primary_window = self.primary_window.single().ok();
+
+        // Cameras are boxed in order of their `order` (and then entity), so the results (and the
+        // messages and logs) don't depend on the query's iteration order. Every camera in a group
+        // is given the boxing of the group's leader, so the leaders are boxed before the rest of
+        // their group. There are only ever a handful of groups, so these are kept in lists rather
+        // than maps.
+        let mut group_leaders: Vec<(GroupKey, (isize, Entity))> = Vec::new();
+        let mut cameras = Vec::new();
+        for (entity, camera, target, .., enabled, _, _, group, _, _) in self.boxed_cameras.iter() {
+            let group_key = group.copied().zip(target.normalize(primary_window));
+            if let Some(group_key) = &group_key
+                && camera.is_active
+                && enabled.is_none_or(|enabled| enabled.0)
+            {
+                let leader = (camera.order, entity);
+                match group_leaders.iter_mut().find(|(key, _)| key == group_key) {
+                    Some((_, current)) => *current = leader.min(*current),
+                    None => group_leaders.push((group_key.clone(), leader)),
+                }
+            }
+            cameras.push((camera.order, entity, group_key));
+        }
+
+        // A group is always boxed as a whole, so when only some cameras are being boxed, the rest
+        // of their groups (including the leaders) are boxed with them.
+        if let Some(only) = only {
+            let only_groups = cameras
+                .iter()
+                .filter(|(_, entity, _)| only.contains(entity))
+                .filter_map(|(_, _, group_key)| group_key.clone())
+                .collect::<Vec<_>>();
+            cameras.retain(|(_, entity, group_key)| {
+                only.contains(entity)
+                    || group_key
+                        .as_ref()
+                        .is_some_and(|group_key| only_groups.contains(group_key))
+            });
+        }
+        cameras.sort_by_key(|(order, entity, group_key)| {
+            let follower = group_key.as_ref().is_some_and(|group_key| {
+                group_leaders
+                    .iter()
+                    .any(|(key, (_, leader))| key == group_key && leader != entity)
+            });
+            (follower, *order, *entity)
+        });
+        let mut group_boxing: Vec<(GroupKey, (bool, Boxing))> = Vec::new();
+
+        'cameras: for (_, entity, group_key) in cameras {
+            let Ok((
+                _,
+                mut camera,
+                target,
+                camera_box,
+                nested_box,
+                computed,
+                pending,
+                anchor,
+                anchored_offset,
+                iris_reveal,
+                enabled,
+                min_viewport_size,
+                transition,
+                _,
+                (aspect_tolerance, scale_factor_override),
+                inactive_box,
+            )) = self.boxed_cameras.get_mut(entity)
+            else {
+                continue;
+            };
+
+            let inactive_box = inactive_box.filter(|_| !camera.is_active);
+            if !camera.is_active && inactive_box.is_none() {
+                continue;
+            }
+
+            // Inactive cameras can't lead their group, so they're boxed with their `InactiveBox`
+            // rather than the group's boxing.
+            let group_key = group_key.filter(|_| camera.is_active);
+
+            let camera_box = match inactive_box {
+                None => camera_box,
+                Some(InactiveBox(Some(inactive_box))) => inactive_box,
+                Some(InactiveBox(None)) => {
+                    clear_viewport(
+                        entity,
+                        &mut camera,
+                        computed,
+                        &mut self.boxing_changed,
+                        &mut self.toggled,
+                        &mut self.camera_boxed,
+                    );
+                    continue;
+                }
+            };
+
+            if enabled.is_some_and(|enabled| !enabled.0) {
+                clear_viewport(
+                    entity,
+                    &mut camera,
+                    computed,
+                    &mut self.boxing_changed,
+                    &mut self.toggled,
+                    &mut self.camera_boxed,
+                );
+                continue;
+            }
+
+            let target = target.normalize(primary_window);
+            let exclusive_fullscreen = match &target {
+                Some(NormalizedRenderTarget::Window(window_ref))
+                    if self.settings.unbox_in_exclusive_fullscreen =>
+                {
+                    self.windows
+                        .get(window_ref.entity())
+                        .is_ok_and(|(_, window)| is_exclusive_fullscreen_at_native(window))
+                }
+                _ => false,
+            };
+
+            let targets_image = matches!(target, Some(NormalizedRenderTarget::Image(_)));
+            let target = match target
+                .map(|t| t.get_render_target_info(&self.windows, &self.images, &self.texture_views))
+            {
+                None => {
+                    info!(
+                        "Failed to get normalized render target! Are you rendering to a Primary Window without having set one?"
+                    );
+                    // The viewport was calculated for a render target that's gone, so it's stale.
+                    clear_viewport(
+                        entity,
+                        &mut camera,
+                        computed,
+                        &mut self.boxing_changed,
+                        &mut self.toggled,
+                        &mut self.camera_boxed,
+                    );
+                    continue;
+                }
+                Some(Err(_)) if targets_image => {
+                    self.errors
+                        .write(BoxingError::TargetMissing { camera: entity });
+                    clear_viewport(
+                        entity,
+                        &mut camera,
+                        computed,
+                        &mut self.boxing_changed,
+                        &mut self.toggled,
+                        &mut self.camera_boxed,
+                    );
+                    continue;
+                }
+                Some(Err(e)) => {
+                    warn_once!("Missing Render Target Info: {:#?}", e);
+                    clear_viewport(
+                        entity,
+                        &mut camera,
+                        computed,
+                        &mut self.boxing_changed,
+                        &mut self.toggled,
+                        &mut self.camera_boxed,
+                    );
+                    continue;
+                }
+                Some(Ok(target)) => target,
+            };
+
+            // Some render targets (like freshly created images) briefly have no size at all, so
+            // we leave the viewport alone until they have a size, which will trigger a recalculation.
+            if target.physical_size == UVec2::ZERO {
+                continue;
+            }
+
+            if exclusive_fullscreen {
+                clear_viewport(
+                    entity,
+                    &mut camera,
+                    computed,
+                    &mut self.boxing_changed,
+                    &mut self.toggled,
+                    &mut self.camera_boxed,
+                );
+                continue;
+            }
+
+            let mut viewport = match &mut camera.viewport {
+                None => Viewport::default(),
+                Some(viewport) => viewport.to_owned(),
+            };
+
+            // A pending target size is only used for a single pass, so we clear it without
+            // triggering change detection, otherwise we would immediately recalculate the boxing.
+            let target_size = match pending {
+                Some(mut pending) => pending
+                    .bypass_change_detection()
+                    .0
+                    .take()
+                    .unwrap_or(target.physical_size),
+                None => target.physical_size,
+            };
+
+            // If the camera only renders a sub view, we box within the part of the render target
+            // the sub view covers instead of the full render target. The sub view is given in the
+            // full view's space, so it's scaled to render target pixels first.
+            let (working_size, working_offset) = match &camera.sub_camera_view {
+                Some(sub_view) if sub_view.full_size.cmpgt(UVec2::ZERO).all() => {
+                    let scale = target_size.as_vec2() / sub_view.full_size.as_vec2();
+                    let offset = (sub_view.offset * scale)
+                        .round()
+                        .as_uvec2()
+                        .min(target_size);
+                    let size = (sub_view.size.as_vec2() * scale)
+                        .round()
+                        .as_uvec2()
+                        .min(target_size - offset);
+                    (size, offset)
+                }
+                _ => (target_size, UVec2::ZERO),
+            };
+
+            // A viewport set by the user alongside a sub view would be placed relative to the
+            // full render target, so we discard it and box within the sub view instead.
+            if camera.sub_camera_view.is_some()
+                && camera.viewport.is_some()
+                && computed
+                    .as_ref()
+                    .is_none_or(|computed| computed.target_size == UVec2::ZERO)
+            {
+                warn_once!(
+                    "Camera {} has both a sub view and a viewport set, the viewport will be replaced by the boxed one",
+                    entity,
+                );
+                viewport = Viewport::default();
+            }
+
+            let aspect_epsilon = aspect_tolerance.map_or(
+                self.settings
+                    .aspect_epsilon
+                    .unwrap_or(DEFAULT_ASPECT_EPSILON),
+                |aspect_tolerance| aspect_tolerance.0,
+            );
+            // Logical bars depend on the render target's scale factor, which is only known here.
+            let scale_factor =
+                scale_factor_override.map_or(target.scale_factor, |scale_factor| scale_factor.0);
+            let physical_bars = physical_bars(camera_box, scale_factor);
+            let camera_box = physical_bars.as_ref().unwrap_or(camera_box);
+            let changes = calculate_changes(
+                camera_box,
+                &working_size,
+                &UVec2::ZERO,
+                &viewport.physical_size,
+                aspect_epsilon,
+            );
+            let (mut boxed, mut boxing) = match changes {
+                None => continue,
+                Some(ViewportChanges::SetToNone) => (
+                    working_size != target_size,
+                    Boxing {
+                        boxing_offset: Vec2::ZERO,
+                        output_resolution: working_size.as_vec2(),
+                    },
+                ),
+                Some(ViewportChanges::Box(boxing)) => (true, boxing),
+                Some(ViewportChanges::Degenerate) => {
+                    clear_viewport(
+                        entity,
+                        &mut camera,
+                        computed,
+                        &mut self.boxing_changed,
+                        &mut self.toggled,
+                        &mut self.camera_boxed,
+                    );
+                    continue;
+                }
+                Some(ViewportChanges::AspectMismatch { expected, actual }) => {
+                    self.errors.write(BoxingError::AspectMismatch {
+                        camera: entity,
+                        expected,
+                        actual,
+                    });
+                    clear_viewport(
+                        entity,
+                        &mut camera,
+                        computed,
+                        &mut self.boxing_changed,
+                        &mut self.toggled,
+                        &mut self.camera_boxed,
+                    );
+                    continue;
+                }
+            };
+
+            if boxed
+                && matches!(
+                    camera_box,
+                    CameraBox::StaticResolution { position: None, .. }
+                        | CameraBox::StaticAspectRatio { position: None, .. }
+                        | CameraBox::ResolutionIntegerScale { .. }
+                        | CameraBox::FixedTiles { .. }
+                        | CameraBox::IntegerAspect { .. }
+                )
+            {
+                let target_size = working_size.as_vec2();
+                if let Some(anchored_offset) = anchored_offset {
+                    boxing.boxing_offset =
+                        anchored_offset.offset(target_size, boxing.output_resolution);
+                } else if let Some(anchor) = anchor {
+                    boxing.boxing_offset = anchor.offset(target_size, boxing.output_resolution);
+                }
+            }
+
+            let mut current_child = nested_box;
+            while let Some(child) = current_child {
+                let Ok((actual_child, next)) = self.loose_boxes.get(child.0) else {
+                    break;
+                };
+
+                match calculate_changes(
+                    actual_child,
+                    &boxing.output_resolution.as_uvec2(),
+                    &boxing.boxing_offset.as_uvec2(),
+                    &boxing.output_resolution.as_uvec2(),
+                    aspect_epsilon,
+                ) {
+                    None => break,
+                    Some(ViewportChanges::SetToNone) => (),
+                    Some(ViewportChanges::Box(nested)) => {
+                        boxing = nested;
+                        boxed = true;
+                    }
+                    Some(ViewportChanges::Degenerate) => {
+                        clear_viewport(
+                            entity,
+                            &mut camera,
+                            computed,
+                            &mut self.boxing_changed,
+                            &mut self.toggled,
+                            &mut self.camera_boxed,
+                        );
+                        continue 'cameras;
+                    }
+                    Some(ViewportChanges::AspectMismatch { expected, actual }) => {
+                        self.errors.write(BoxingError::AspectMismatch {
+                            camera: entity,
+                            expected,
+                            actual,
+                        });
+                        clear_viewport(
+                            entity,
+                            &mut camera,
+                            computed,
+                            &mut self.boxing_changed,
+                            &mut self.toggled,
+                            &mut self.camera_boxed,
+                        );
+                        continue 'cameras;
+                    }
+                };
+                current_child = next;
+            }
+
+            if boxed {
+                boxing =
+                    apply_min_content_size(boxing, self.settings.min_content_size, working_size);
+                if let Some(max_bar_size) = self.settings.max_bar_size {
+                    boxing = apply_max_bar_size(boxing, max_bar_size, working_size);
+                }
+                if self.settings.even_dimensions {
+                    boxing = make_even_dimensions(boxing, working_size);
+                }
+            }
+
+            if boxed
+                && let Some(min_viewport_size) = min_viewport_size
+                && boxing
+                    .output_resolution
+                    .cmplt(min_viewport_size.size.as_vec2())
+                    .any()
+            {
+                match min_viewport_size.behavior {
+                    MinViewportBehavior::Disable => {
+                        boxed = working_size != target_size;
+                        boxing = Boxing {
+                            boxing_offset: Vec2::ZERO,
+                            output_resolution: working_size.as_vec2(),
+                        };
+                    }
+                    MinViewportBehavior::Clamp => {
+                        warn_once!(
+                            "Boxed output with size {} is smaller than the minimum viewport size {}, growing it to fit",
+                            boxing.output_resolution,
+                            min_viewport_size.size,
+                        );
+                        boxing =
+                            apply_min_content_size(boxing, min_viewport_size.size, working_size);
+                    }
+                }
+            }
+
+            if let Some(mut transition) = transition {
+                // The transition's progress is internal state, so changing it shouldn't be seen as
+                // the camera's configuration changing.
+                let transition = transition.bypass_change_detection();
+                boxing = transition.retarget(boxing);
+                boxed |= !transition.is_finished();
+            }
+
+            let revealing = iris_reveal.is_some_and(|iris_reveal| iris_reveal.fraction() < 1.);
+            if let Some(iris_reveal) = iris_reveal
+                && revealing
+            {
+                boxing = iris_reveal.shrink(boxing);
+                boxed = true;
+            }
+
+            if boxed {
+                boxing = round_boxing(boxing, working_size, self.settings.rounding);
+            }
+
+            if let Some(group_key) = group_key {
+                match group_boxing.iter().find(|(key, _)| *key == group_key) {
+                    Some((_, leader_boxing)) => (boxed, boxing) = *leader_boxing,
+                    None => group_boxing.push((group_key, (boxed, boxing))),
+                }
+            }
+
+            if boxed && self.settings.assert_viewports {
+                debug_assert_viewport(&boxing, working_size, revealing);
+            }
+            boxing.boxing_offset += working_offset.as_vec2();
+
+            let previous_viewport = viewport_key(&camera.viewport);
+            if boxed {
+                viewport.physical_size = boxing.output_resolution.as_uvec2();
+                viewport.physical_position = boxing.boxing_offset.as_uvec2();
+                camera.viewport = Some(viewport);
+            } else if self.settings.on_no_box == NoBoxBehavior::ClearViewport {
+                camera.viewport = None;
+            }
+            if viewport_key(&camera.viewport) != previous_viewport {
+                if let Some(boxing_changed) = &mut self.boxing_changed {
+                    boxing_changed.0 = true;
+                }
+                self.camera_boxed.write(CameraBoxed {
+                    camera: entity,
+                    viewport: camera.viewport.clone(),
+                    boxing_offset: boxing.boxing_offset,
+                    output_resolution: boxing.output_resolution,
+                });
+            }
+
+            let computed_boxing = ComputedBoxing {
+                target_size,
+                boxing_offset: boxing.boxing_offset,
+                output_resolution: boxing.output_resolution,
+                remainder: working_size.as_vec2() - boxing.output_resolution,
+                used_imperfect_scaling: boxed && uses_imperfect_scaling(camera_box, working_size),
+            };
+            let was_boxing = computed
+                .as_ref()
+                .is_some_and(|computed| computed.is_boxed());
+            let now_boxing = computed_boxing.is_boxed();
+            if was_boxing != now_boxing {
+                self.toggled.write(BoxingToggled {
+                    camera: entity,
+                    now_boxing,
+                });
+            }
+
+            match computed {
+                Some(mut computed) => {
+                    computed.set_if_neq(computed_boxing);
+                }
+                None => {
+                    self.commands.entity(entity).try_insert(computed_boxing);
+                }
+            }
+        }
+    }
+}
+
+/// The group of a camera, along with the render target it renders to.
+type GroupKey = (CameraBoxGroup, NormalizedRenderTarget);
+
+/// The parts of a viewport that are compared to see if it has changed.
+fn viewport_key(viewport: &Option<Viewport>) -> Option<(UVec2, UVec2, core::ops::Range<f32>)> {
+    viewport.as_ref().map(|viewport| {
+        (
+            viewport.physical_position,
+            viewport.physical_size,
+            viewport.depth.clone(),
+        )
+    })
+}
+
+fn adjust_viewport(mut boxing: ViewportBoxing) {
+    boxing.adjust(None);
+}
+
+fn adjust_viewport_for(mut requests: MessageReader<AdjustBoxingFor>, mut boxing: ViewportBoxing) {
+    let cameras = requests.read().map(|request| request.0).collect::<Vec<_>>();
+    boxing.adjust(Some(&cameras));
+}
+
+/// Clears the viewport and `ComputedBoxing` of a camera that can't be boxed (e.g. because its
+/// boxing is disabled), sending a `BoxingToggled` if it was boxed and a `CameraBoxed` if it had a
+/// viewport.
+fn clear_viewport(
+    entity: Entity,
+    camera: &mut Mut<Camera>,
+    computed: Option<Mut<ComputedBoxing>>,
+    boxing_changed: &mut Option<ResMut<BoxingChangedThisFrame>>,
+    toggled: &mut MessageWriter<BoxingToggled>,
+    camera_boxed: &mut MessageWriter<CameraBoxed>,
+) {
+    if let Some(mut computed) = computed {
+        if computed.is_boxed() {
+            toggled.write(BoxingToggled {
+                camera: entity,
+                now_boxing: false,
+            });
+        }
+        computed.set_if_neq(ComputedBoxing::default());
+    }
+    if camera.viewport.is_none() {
+        return;
+    }
+
+    camera.viewport = None;
+    if let Some(boxing_changed) = boxing_changed {
+        boxing_changed.0 = true;
+    }
+    camera_boxed.write(CameraBoxed {
+        camera: entity,
+        viewport: None,
+        boxing_offset: Vec2::ZERO,
+        output_resolution: Vec2::ZERO,
+    });
+}
+
+/// Panics in debug builds if `boxing` doesn't fit within `target_size`, or if it's empty and
+/// `allow_empty` is false.
+fn debug_assert_viewport(boxing: &Boxing, target_size: UVec2, allow_empty: bool) {
+    let end = boxing.boxing_offset + boxing.output_resolution;
+    debug_assert!(
+        boxing.boxing_offset.cmpge(Vec2::ZERO).all() && end.cmple(target_size.as_vec2()).all(),
+        "Boxed viewport at {} with size {} doesn't fit within render target with size {}",
+        boxing.boxing_offset,
+        boxing.output_resolution,
+        target_size,
+    );
+    debug_assert!(
+        allow_empty || boxing.output_resolution.as_uvec2().cmpne(UVec2::ZERO).all(),
+        "Boxed viewport at {} has an empty size {}",
+        boxing.boxing_offset,
+        boxing.output_resolution,
+    );
+}
+
+/// Grows the boxed output (around its center) so that it is at least `min_size`, without growing
+/// past the render target.
+fn apply_min_content_size(boxing: Boxing, min_size: UVec2, target_size: UVec2) -> Boxing {
+    let target_size = target_size.as_vec2();
+    let output_resolution = boxing
+        .output_resolution
+        .max(min_size.as_vec2().min(target_size));
+    let growth = output_resolution - boxing.output_resolution;
+    let boxing_offset = (boxing.boxing_offset - (growth / 2.).floor())
+        .clamp(Vec2::ZERO, target_size - output_resolution);
+
+    Boxing {
+        boxing_offset,
+        output_resolution,
+    }
+}
+
+/// Shrinks every bar that is thicker than `max_size` down to `max_size`, growing the boxed output to
+/// fill the space.
+fn apply_max_bar_size(boxing: Boxing, max_size: u32, target_size: UVec2) -> Boxing {
+    let max_size = Vec2::splat(max_size as f32);
+    let far_bars = target_size.as_vec2() - boxing.boxing_offset - boxing.output_resolution;
+    let boxing_offset = boxing.boxing_offset.min(max_size);
+    let far_bars = far_bars.min(max_size);
+
+    Boxing {
+        boxing_offset,
+        output_resolution: target_size.as_vec2() - boxing_offset - far_bars,
+    }
+}
+
+/// Shrinks the boxed output so it has an even width and height, giving the removed pixel to the
+/// smaller bar so the bars stay as even as possible.
+fn make_even_dimensions(boxing: Boxing, target_size: UVec2) -> Boxing {
+    let boxing_offset = boxing.boxing_offset.floor();
+    let output_resolution = boxing.output_resolution.floor();
+    let even_resolution = (output_resolution / 2.).floor() * 2.;
+    let far_bars = target_size.as_vec2() - boxing_offset - output_resolution;
+
+    Boxing {
+        boxing_offset: boxing_offset
+            + Vec2::select(
+                boxing_offset.cmplt(far_bars),
+                output_resolution - even_resolution,
+                Vec2::ZERO,
+            ),
+        output_resolution: even_resolution,
+    }
+}
+
+/// Rounds the boxed output to whole pixels according to `rounding`.
+fn round_boxing(boxing: Boxing, target_size: UVec2, rounding: BoxRounding) -> Boxing {
+    let target_size = target_size.as_vec2();
+    let (boxing_offset, output_resolution) = match rounding {
+        // The viewport already truncates the boxing, so there's nothing to do.
+        BoxRounding::Floor => return boxing,
+        BoxRounding::Round => (
+            boxing.boxing_offset.round(),
+            boxing.output_resolution.round(),
+        ),
+        BoxRounding::Ceil => (boxing.boxing_offset.ceil(), boxing.output_resolution.ceil()),
+        BoxRounding::SymmetricPad => {
+            let output_resolution = boxing.output_resolution.round().min(target_size);
+            let far_bars = target_size - boxing.boxing_offset - boxing.output_resolution;
+            let centered = (boxing.boxing_offset - far_bars).abs().cmple(Vec2::ONE);
+            (
+                Vec2::select(
+                    centered,
+                    ((target_size - output_resolution) / 2.).floor(),
+                    boxing.boxing_offset.floor(),
+                ),
+                output_resolution,
+            )
+        }
+    };
+
+    let boxing_offset = boxing_offset.clamp(Vec2::ZERO, target_size);
+    Boxing {
+        boxing_offset,
+        output_resolution: output_resolution.min(target_size - boxing_offset),
+    }
+}
+
+/// Scales a `CameraBox::StaticResolution` resolution to the output according to `scale`.
+fn scale_resolution(
+    resolution: &UVec2,
+    physical_resolution: &UVec2,
+    scale: ResolutionScaleMode,
+) -> UVec2 {
+    if resolution.cmpeq(UVec2::ZERO).any() {
+        return *resolution;
+    }
+
+    match scale {
+        ResolutionScaleMode::Exact => *resolution,
+        ResolutionScaleMode::IntegerFit => {
+            let scale = (physical_resolution / resolution).min_element().max(1);
+            resolution * scale
+        }
+        ResolutionScaleMode::StretchFit => {
+            let scale = (physical_resolution.as_vec2() / resolution.as_vec2()).min_element();
+            (resolution.as_vec2() * scale)
+                .round()
+                .as_uvec2()
+                .min(*physical_resolution)
+        }
+    }
+}
+
+/// Converts the bars of a `CameraBox::LetterBox` or `CameraBox::PillarBox` with `logical_bars` to
+/// physical pixels, returning None if `camera_box` doesn't have logical bars.
+///
+/// Both bars are rounded together, so they always add up to their rounded total, and the first bar
+/// is given the smaller half when they can't be split evenly.
+fn physical_bars(camera_box: &CameraBox, scale_factor: f32) -> Option<CameraBox> {
+    let to_physical = |first: u32, second: u32| {
+        let logical_total = first as u64 + second as u64;
+        if logical_total == 0 {
+            return (0, 0);
+        }
+
+        let total = (logical_total as f32 * scale_factor).round() as u64;
+        let first = total * first as u64 / logical_total;
+        (first as u32, (total - first) as u32)
+    };
+
+    match camera_box {
+        CameraBox::LetterBox {
+            top,
+            bottom,
+            strict_letterboxing,
+            preserve_bar_ratio,
+            logical_bars: true,
+        } => {
+            let (top, bottom) = to_physical(*top, *bottom);
+            Some(CameraBox::LetterBox {
+                top,
+                bottom,
+                strict_letterboxing: *strict_letterboxing,
+                preserve_bar_ratio: *preserve_bar_ratio,
+                logical_bars: false,
+            })
+        }
+        CameraBox::PillarBox {
+            left,
+            right,
+            strict_pillarboxing,
+            logical_bars: true,
+        } => {
+            let (left, right) = to_physical(*left, *right);
+            Some(CameraBox::PillarBox {
+                left,
+                right,
+                strict_pillarboxing: *strict_pillarboxing,
+                logical_bars: false,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn calculate_changes(
+    camerabox: &CameraBox,
+    physical_resolution: &UVec2,
+    render_placement: &UVec2,
+    render_size: &UVec2,
+    aspect_epsilon: f32,
+) -> Option<ViewportChanges> {
+    match &camerabox {
+        CameraBox::StaticResolution {
+            resolution,
+            position,
+            scale,
+        } => {
+            let resolution = &scale_resolution(resolution, physical_resolution, *scale);
+
+            // The size of the region that actually fits within the render target, which is what
+            // any position has to be checked against.
+            let fitted_resolution = resolution.clamp(UVec2::ZERO, *physical_resolution);
+
+            if physical_resolution == resolution && position.is_none() {
+                return Some(ViewportChanges::SetToNone);
+            } else if let Some(position) = position
+                && !is_within_rect(physical_resolution, position, &fitted_resolution)
+                && render_placement == &UVec2::ZERO
+            {
+                return None;
+            }
+
+            let clamped_resolution = if render_size != resolution {
+                &resolution.clamp(UVec2::ONE, *physical_resolution)
+            } else {
+                resolution
+            };
+
+            let render_placement = if position.is_none() {
+                (physical_resolution - fitted_resolution) / 2
+            } else {
+                let position = position.unwrap();
+                if is_within_rect(physical_resolution, &position, &fitted_resolution) {
+                    position
+                } else {
+                    warn_once!(
+                        "Unable to place output with resolution {} at position {} within Render Target with size {}. Placing at (0,0) instead",
+                        resolution,
+                        position,
+                        physical_resolution
+                    );
+                    UVec2::ZERO
+                }
+            } + render_placement;
+            Some(ViewportChanges::Box(Boxing {
+                boxing_offset: render_placement.as_vec2(),
+                output_resolution: clamped_resolution.as_vec2(),
+            }))
+        }
+        CameraBox::StaticAspectRatio {
+            aspect_ratio,
+            position,
+            strict,
+        } => {
+            let physical_aspect_ratio = match AspectRatio::try_from(physical_resolution.as_vec2()) {
+                // Aspect Ratios are rarely exact, so ones within the epsilon are treated as equal.
+                Ok(ar) if (ar.ratio() - aspect_ratio.ratio()).abs() <= aspect_epsilon => {
+                    return Some(ViewportChanges::SetToNone);
+                }
+                Ok(ar) if *strict => {
+                    return Some(ViewportChanges::AspectMismatch {
+                        expected: *aspect_ratio,
+                        actual: ar,
+                    });
+                }
+                Err(e) => {
+                    // A degenerate render target can't be boxed, so we clear the viewport rather
+                    // than leaving a viewport around that was calculated for an older size.
+                    warn!(
+                        "Error occurred when calculating aspect ratios for scaling: {:?}",
+                        e
+                    );
+                    return Some(ViewportChanges::Degenerate);
+                }
+                Ok(ar) => ar,
+            };
+
+            let Boxing {
+                boxing_offset,
+                output_resolution,
+            } = calculate_boxing_from_aspect_ratios(
+                &physical_resolution.as_vec2(),
+                &physical_aspect_ratio,
+                aspect_ratio,
+            );
+
+            Some(ViewportChanges::Box(Boxing {
+                output_resolution,
+                boxing_offset: match position {
+                    None => boxing_offset + render_placement.as_vec2(),
+                    Some(pos) => {
+                        (if is_within_rect(physical_resolution, pos, render_size) {
+                            pos.as_vec2()
+                        } else {
+                            warn_once!(
+                                "Unable to place output with resolution {} at position {} within Render Target with size {}. Placing at (0,0) instead",
+                                output_resolution,
+                                pos,
+                                physical_resolution,
+                            );
+                            Vec2::ZERO
+                        }) + render_placement.as_vec2()
+                    }
+                },
+            }))
+        }
+        CameraBox::ResolutionIntegerScale {
+            resolution,
+            allow_imperfect_downscaled_boxing,
+            independent_axis_scale,
+            fit,
+            force_downscale,
+            min_content_fraction,
+        } => {
+            let physical_size = &physical_resolution.as_vec2();
+            let resolution = &resolution.as_vec2();
+            let path = integer_scale_path(
+                physical_size,
+                resolution,
+                *allow_imperfect_downscaled_boxing,
+                *independent_axis_scale,
+                *fit,
+                *force_downscale,
+                *min_content_fraction,
+            );
+            integer_scale_changes(
+                match path {
+                    IntegerScalePath::Downscaled => {
+                        calculate_boxing_downscaled(physical_size, resolution)
+                    }
+                    IntegerScalePath::Cropped => Ok(None),
+                    IntegerScalePath::IndependentAxes => {
+                        calculate_boxing_independent_axes(physical_size, resolution)
+                    }
+                    IntegerScalePath::Nearest => {
+                        calculate_boxing_nearest(physical_size, resolution)
+                    }
+                    IntegerScalePath::Imperfect => {
+                        calculate_boxing_imperfect(physical_size, resolution)
+                    }
+                    IntegerScalePath::Perfect => {
+                        calculate_boxing_perfect(physical_size, resolution)
+                    }
+                },
+                render_placement,
+            )
+        }
+        CameraBox::FixedTiles {
+            tile_size,
+            cols,
+            rows,
+        } => integer_scale_changes(
+            calculate_boxing_perfect(
+                &physical_resolution.as_vec2(),
+                &(tile_size.saturating_mul(UVec2::new(*cols, *rows))).as_vec2(),
+            ),
+            render_placement,
+        ),
+        CameraBox::IntegerAspect { base } => {
+            let aspect_ratio = match AspectRatio::try_from(base.as_vec2()) {
+                Ok(aspect_ratio) => aspect_ratio,
+                Err(e) => {
+                    warn!(
+                        "Error occurred when calculating the base's aspect ratio: {:?}",
+                        e
+                    );
+                    return Some(ViewportChanges::SetToNone);
+                }
+            };
+
+            let scale = (physical_resolution / base).min_element();
+            if scale == 0 {
+                // No Integer Scale fits, so we keep the Aspect Ratio as best we can instead.
+                return calculate_changes(
+                    &CameraBox::static_aspect_ratio(aspect_ratio),
+                    physical_resolution,
+                    render_placement,
+                    render_size,
+                    aspect_epsilon,
+                );
+            }
+
+            let output_resolution = base * scale;
+            integer_scale_changes(
+                Ok((output_resolution != *physical_resolution).then(|| Boxing {
+                    boxing_offset: ((physical_resolution - output_resolution) / 2).as_vec2(),
+                    output_resolution: output_resolution.as_vec2(),
+                })),
+                render_placement,
+            )
+        }
+        CameraBox::LetterBox {
+            top,
+            bottom,
+            strict_letterboxing,
+            preserve_bar_ratio,
+            ..
+        } => {
+            let Boxing {
+                mut boxing_offset,
+                mut output_resolution,
+            } = calculate_letterbox(&physical_resolution.as_vec2(), (top, bottom));
+            if (output_resolution.y + boxing_offset.y > physical_resolution.y as f32
+                || output_resolution.y <= 0.)
+                && !strict_letterboxing
+            {
+                output_resolution.y = physical_resolution.y as f32 / 2.;
+                if *preserve_bar_ratio && top.saturating_add(*bottom) > 0 {
+                    boxing_offset.y =
+                        output_resolution.y * (*top as f32 / (*top as f32 + *bottom as f32));
+                } else {
+                    boxing_offset.y /= 2.;
+                    let scale_factor =
+                        (physical_resolution.y as f32) / (output_resolution.y + boxing_offset.y);
+                    boxing_offset.y *= scale_factor;
+                }
+            }
+
+            if (output_resolution.y <= 0.
+                || output_resolution.y > physical_resolution.y as f32
+                || output_resolution.y + boxing_offset.y > physical_resolution.y as f32)
+                && *strict_letterboxing
+            {
+                return Some(ViewportChanges::SetToNone);
+            }
+
+            // Bars much larger than the render target can push the output past its far edge.
+            boxing_offset = boxing_offset.min(physical_resolution.as_vec2() - output_resolution);
+            Some(ViewportChanges::Box(Boxing {
+                boxing_offset: boxing_offset + render_placement.as_vec2(),
+                output_resolution,
+            }))
+        }
+        CameraBox::PillarBox {
+            left,
+            right,
+            strict_pillarboxing,
+            ..
+        } => {
+            let Boxing {
+                mut boxing_offset,
+                mut output_resolution,
+            } = calculate_pillarbox(&physical_resolution.as_vec2(), (left, right));
+
+            if (output_resolution.x + boxing_offset.x > physical_resolution.x as f32
+                || output_resolution.x <= 0.)
+                && !strict_pillarboxing
+            {
+                output_resolution.x = physical_resolution.x as f32 / 2.;
+                boxing_offset.x /= 2.;
+                let scale_factor =
+                    (physical_resolution.x as f32) / (output_resolution.x + boxing_offset.x);
+                boxing_offset.x *= scale_factor;
+            }
+
+            if (output_resolution.x <= 0.
+                || output_resolution.x > physical_resolution.x as f32
+                || output_resolution.x + boxing_offset.x > physical_resolution.x as f32)
+                && *strict_pillarboxing
+            {
+                return Some(ViewportChanges::SetToNone);
+            }
+
+            // Bars much larger than the render target can push the output past its far edge.
+            boxing_offset = boxing_offset.min(physical_resolution.as_vec2() - output_resolution);
+            Some(ViewportChanges::Box(Boxing {
+                boxing_offset: boxing_offset + render_placement.as_vec2(),
+                output_resolution,
+            }))
+        }
+        CameraBox::WindowBox {
+            left,
+            right,
+            top,
+            bottom,
+            strict_windowboxing,
+        } => {
+            let letterboxing = (top, bottom);
+            let pillarboxing = (left, right);
+
+            let Boxing {
+                mut boxing_offset,
+                mut output_resolution,
+            } = calculate_windowbox(&physical_resolution.as_vec2(), [letterboxing, pillarboxing]);
+
+            if *strict_windowboxing {
+                if output_resolution.x <= 0.
+                    || !is_within_rect(
+                        physical_resolution,
+                        &boxing_offset.as_uvec2(),
+                        &output_resolution.as_uvec2(),
+                    )
+                {
+                    return Some(ViewportChanges::SetToNone);
+                }
+            } else {
+                if output_resolution.x + boxing_offset.x > physical_resolution.x as f32
+                    || output_resolution.x <= 0.
+                {
+                    output_resolution.x = physical_resolution.x as f32 / 2.;
+                    boxing_offset.x /= 2.;
+                    let scale_factor =
+                        (physical_resolution.x as f32) / (output_resolution.x + boxing_offset.x);
+                    boxing_offset.x *= scale_factor;
+                }
+
+                if output_resolution.y + boxing_offset.y > physical_resolution.y as f32
+                    || output_resolution.y <= 0.
+                {
+                    output_resolution.y = physical_resolution.y as f32 / 2.;
+                    boxing_offset.y /= 2.;
+                    let scale_factor =
+                        (physical_resolution.y as f32) / (output_resolution.y + boxing_offset.y);
+                    boxing_offset.y *= scale_factor;
+                }
+            }
+
+            // Bars much larger than the render target can push the output past its far edge.
+            boxing_offset = boxing_offset.min(physical_resolution.as_vec2() - output_resolution);
+            Some(ViewportChanges::Box(Boxing {
+                output_resolution,
+                boxing_offset: boxing_offset + render_placement.as_vec2(),
+            }))
+        }
+        CameraBox::CutsceneLetterbox { video_size } => {
+            match AspectRatio::try_from(video_size.as_vec2()) {
+                Ok(aspect_ratio) => calculate_changes(
+                    &CameraBox::StaticAspectRatio {
+                        aspect_ratio,
+                        position: None,
+                        strict: false,
+                    },
+                    physical_resolution,
+                    render_placement,
+                    render_size,
+                    aspect_epsilon,
+                ),
+                Err(e) => {
+                    warn!(
+                        "Error occurred when calculating the video's aspect ratio: {:?}",
+                        e
+                    );
+                    Some(ViewportChanges::SetToNone)
+                }
+            }
+        }
+        CameraBox::AspectRatioWithMargin {
+            aspect_ratio,
+            margin,
+        } => {
+            let margins =
+                UVec2::new(margin.y, margin.x).saturating_add(UVec2::new(margin.w, margin.z));
+            if margins.cmpge(*physical_resolution).any() {
+                warn_once!(
+                    "Margins of {} leave no room within Render Target with size {}, not boxing",
+                    margin,
+                    physical_resolution
+                );
+                return Some(ViewportChanges::SetToNone);
+            }
+
+            let area_size = physical_resolution - margins;
+            let area_placement = render_placement + UVec2::new(margin.w, margin.x);
+            match calculate_changes(
+                &CameraBox::static_aspect_ratio(*aspect_ratio),
+                &area_size,
+                &area_placement,
+                &area_size,
+                aspect_epsilon,
+            )? {
+                ViewportChanges::SetToNone if margins != UVec2::ZERO => {
+                    Some(ViewportChanges::Box(Boxing {
+                        boxing_offset: area_placement.as_vec2(),
+                        output_resolution: area_size.as_vec2(),
+                    }))
+                }
+                changes => Some(changes),
+            }
+        }
+        CameraBox::XrViews {
+            count,
+            view_index,
+            inner,
+        } => {
+            if view_index >= count {
+                warn_once!(
+                    "XR view index {} is out of range for {} views, not boxing",
+                    view_index,
+                    count
+                );
+                return Some(ViewportChanges::SetToNone);
+            }
+
+            let view_size = UVec2::new(physical_resolution.x / count, physical_resolution.y);
+            let view_placement = render_placement + UVec2::new(view_size.x * view_index, 0);
+            match calculate_changes(
+                inner,
+                &view_size,
+                &view_placement,
+                &view_size,
+                aspect_epsilon,
+            )? {
+                ViewportChanges::SetToNone => Some(ViewportChanges::Box(Boxing {
+                    boxing_offset: view_placement.as_vec2(),
+                    output_resolution: view_size.as_vec2(),
+                })),
+                changes => Some(changes),
+            }
+        }
+        CameraBox::FixedContentHeight { height } => {
+            if *height == 0 || *height >= physical_resolution.y {
+                return Some(ViewportChanges::SetToNone);
+            }
+
+            Some(ViewportChanges::Box(Boxing {
+                boxing_offset: Vec2::new(0., ((physical_resolution.y - height) / 2) as f32)
+                    + render_placement.as_vec2(),
+                output_resolution: Vec2::new(physical_resolution.x as f32, *height as f32),
+            }))
+        }
+        CameraBox::FixedContentWidth { width } => {
+            if *width == 0 || *width >= physical_resolution.x {
+                return Some(ViewportChanges::SetToNone);
+            }
+
+            Some(ViewportChanges::Box(Boxing {
+                boxing_offset: Vec2::new(((physical_resolution.x - width) / 2) as f32, 0.)
+                    + render_placement.as_vec2(),
+                output_resolution: Vec2::new(*width as f32, physical_resolution.y as f32),
+            }))
+        }
+        // The projection takes care of the world height, so the only boxing is to stop the output
+        // from becoming narrower than it is tall.
+        CameraBox::ConstantVerticalExtent { .. } => calculate_changes(
+            &CameraBox::FixedContentHeight {
+                height: physical_resolution.x,
+            },
+            physical_resolution,
+            render_placement,
+            render_size,
+            aspect_epsilon,
+        ),
+    }
+}
+
+/// Which calculation `CameraBox::ResolutionIntegerScale` uses for a render target.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum IntegerScalePath {
+    Downscaled,
+    Cropped,
+    IndependentAxes,
+    Nearest,
+    Imperfect,
+    Perfect,
+}
+
+/// Picks the calculation `CameraBox::ResolutionIntegerScale` uses to scale `resolution` to
+/// `physical_size`, based on its settings.
+fn integer_scale_path(
+    physical_size: &Vec2,
+    resolution: &Vec2,
+    allow_imperfect_downscaled_boxing: bool,
+    independent_axis_scale: bool,
+    fit: IntegerScaleFit,
+    force_downscale: bool,
+    min_content_fraction: Option<f32>,
+) -> IntegerScalePath {
+    if force_downscale {
+        IntegerScalePath::Downscaled
+    } else if fit == IntegerScaleFit::AreaMatch
+        && !independent_axis_scale
+        && prefers_cropped_scale(physical_size, resolution)
+    {
+        IntegerScalePath::Cropped
+    } else if let Some(min_content_fraction) = min_content_fraction
+        && !independent_axis_scale
+        && covers_too_little(physical_size, resolution, min_content_fraction)
+    {
+        IntegerScalePath::Cropped
+    } else if independent_axis_scale {
+        IntegerScalePath::IndependentAxes
+    } else if fit == IntegerScaleFit::Nearest && (physical_size / resolution).min_element() >= 1. {
+        IntegerScalePath::Nearest
+    } else if allow_imperfect_downscaled_boxing {
+        IntegerScalePath::Imperfect
+    } else {
+        IntegerScalePath::Perfect
+    }
+}
+
+/// Checks if `camera_box` falls back to imperfect downscaled boxing on a render target of
+/// `physical_size` (see `allow_imperfect_downscaled_boxing` on
+/// `CameraBox::ResolutionIntegerScale`), rather than boxing to an Integer Scale.
+fn uses_imperfect_scaling(camera_box: &CameraBox, physical_size: UVec2) -> bool {
+    let CameraBox::ResolutionIntegerScale {
+        resolution,
+        allow_imperfect_downscaled_boxing,
+        independent_axis_scale,
+        fit,
+        force_downscale,
+        min_content_fraction,
+    } = camera_box
+    else {
+        return false;
+    };
+
+    let physical_size = &physical_size.as_vec2();
+    let resolution = &resolution.as_vec2();
+    let path = integer_scale_path(
+        physical_size,
+        resolution,
+        *allow_imperfect_downscaled_boxing,
+        *independent_axis_scale,
+        *fit,
+        *force_downscale,
+        *min_content_fraction,
+    );
+
+    // When the resolution fits, the imperfect calculation gives the same Integer Scale as the
+    // perfect one, so it's only imperfect when scaling down.
+    path == IntegerScalePath::Imperfect
+        && (physical_size / resolution).min_element() < 1.
+        && matches!(
+            calculate_boxing_imperfect(physical_size, resolution),
+            Ok(Some(_))
+        )
+}
+
+fn integer_scale_changes(
+    boxing: Result<Option<Boxing>>,
+    render_placement: &UVec2,
+) -> Option<ViewportChanges> {
+    match boxing {
+        Ok(None) => Some(ViewportChanges::SetToNone),
+        Ok(Some(Boxing {
+            boxing_offset,
+            output_resolution,
+        })) => Some(ViewportChanges::Box(Boxing {
+            boxing_offset: render_placement.as_vec2() + boxing_offset,
+            output_resolution,
+        })),
+        Err(e) => {
+            warn!(
+                "Error occurred when calculating aspect ratios for scaling: {:?}",
+                e
+            );
+            Some(ViewportChanges::Degenerate)
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+/// The result of a Boxing calculation, this is what gets turned into a `Viewport`.
+pub struct Boxing {
+    /// The offset of the boxed output from the top-left corner of the render target.
+    pub boxing_offset: Vec2,
+
+    /// The size of the boxed output.
+    pub output_resolution: Vec2,
+}
+
+impl Boxing {
+    /// Linearly interpolates between this and `other`, with `t` clamped between 0 and 1.
+    /// This is useful for transitioning from one Boxing to another over time.
+    pub fn lerp(&self, other: &Boxing, t: f32) -> Boxing {
+        let t = t.clamp(0., 1.);
+        Boxing {
+            boxing_offset: self.boxing_offset.lerp(other.boxing_offset, t),
+            output_resolution: self.output_resolution.lerp(other.output_resolution, t),
+        }
+    }
+}
+
+/// The boxing math used by the plugin, for use without the plugin's systems (e.g. in a custom
+/// render pass).
+///
+/// All sizes are in physical pixels, and the returned `Boxing` is relative to the top-left corner
+/// of `physical_size`. Bars that don't fit within `physical_size` give a negative output
+/// resolution on that axis.
+pub mod boxing {
+    use super::*;
+
+    pub use super::Boxing;
+
+    /// Boxes `physical_size`, which has the Aspect Ratio `physical_aspect_ratio`, to
+    /// `target_aspect_ratio`. This is what `CameraBox::StaticAspectRatio` uses.
+    pub fn from_aspect_ratios(
+        physical_size: Vec2,
+        physical_aspect_ratio: &AspectRatio,
+        target_aspect_ratio: &AspectRatio,
+    ) -> Boxing {
+        calculate_boxing_from_aspect_ratios(
+            &physical_size,
+            physical_aspect_ratio,
+            target_aspect_ratio,
+        )
+    }
+
+    /// Letterboxes `physical_size` with bars of `top` and `bottom` pixels.
+    /// This is what `CameraBox::LetterBox` uses before any scaling or strictness is applied.
+    pub fn letterbox(physical_size: Vec2, top: u32, bottom: u32) -> Boxing {
+        calculate_letterbox(&physical_size, (&top, &bottom))
+    }
+
+    /// Pillarboxes `physical_size` with bars of `left` and `right` pixels.
+    /// This is what `CameraBox::PillarBox` uses before any scaling or strictness is applied.
+    pub fn pillarbox(physical_size: Vec2, left: u32, right: u32) -> Boxing {
+        calculate_pillarbox(&physical_size, (&left, &right))
+    }
+
+    /// Windowboxes `physical_size` with bars of `top`, `bottom`, `left`, and `right` pixels.
+    /// This is what `CameraBox::WindowBox` uses before any scaling is applied.
+    pub fn windowbox(physical_size: Vec2, top: u32, bottom: u32, left: u32, right: u32) -> Boxing {
+        calculate_windowbox(&physical_size, [(&top, &bottom), (&left, &right)])
+    }
+
+    /// Boxes `physical_size` to an Integer Scale of `desired_size`, making sure the Aspect Ratio
+    /// stays as exact as possible when `physical_size` is smaller than `desired_size`.
+    ///
+    /// This returns None if `physical_size` is already an Integer Scale of `desired_size`.
+    pub fn integer_scale_perfect(
+        physical_size: Vec2,
+        desired_size: Vec2,
+    ) -> Result<Option<Boxing>> {
+        calculate_boxing_perfect(&physical_size, &desired_size)
+    }
+
+    /// Boxes `physical_size` to an Integer Scale of `desired_size`, allowing the Aspect Ratio to
+    /// be inexact when `physical_size` is smaller than `desired_size`.
+    ///
+    /// This returns None if `physical_size` is already an Integer Scale of `desired_size`.
+    pub fn integer_scale_imperfect(
+        physical_size: Vec2,
+        desired_size: Vec2,
+    ) -> Result<Option<Boxing>> {
+        calculate_boxing_imperfect(&physical_size, &desired_size)
+    }
+}
+
+fn calculate_boxing_from_aspect_ratios(
+    physical_size: &Vec2,
+    physical_aspect_ratio: &AspectRatio,
+    target_aspect_ratio: &AspectRatio,
+) -> Boxing {
+    // The output size is rounded, and the bars are calculated from it, since the aspect ratio
+    // isn't exact. Otherwise large targets (like 8K) can end up with bars that are a pixel or two
+    // different in size.
+    if physical_aspect_ratio.ratio() > target_aspect_ratio.ratio() {
+        let render_height = physical_size.y;
+        let render_width = (render_height * target_aspect_ratio.ratio()).round();
+        Boxing {
+            boxing_offset: Vec2::new(((physical_size.x - render_width) / 2.).floor(), 0.),
+            output_resolution: Vec2::new(render_width, render_height),
+        }
+    } else {
+        let render_width = physical_size.x;
+        let render_height = (render_width / target_aspect_ratio.ratio()).round();
+        Boxing {
+            boxing_offset: Vec2::new(0., ((physical_size.y - render_height) / 2.).floor()),
+            output_resolution: Vec2::new(render_width, render_height),
+        }
+    }
+}
+fn calculate_boxing_imperfect(physical_size: &Vec2, desired_size: &Vec2) -> Result<Option<Boxing>> {
+    let desired_aspect_ratio = AspectRatio::try_from(*desired_size)?;
+    let physical_aspect_ratio = AspectRatio::try_from(*physical_size)?;
+
+    //NOTE: this does not really handle the case where the target size is smaller than the desired height/width.
+    let height_scale = physical_size.y / desired_size.y;
+    let width_scale = physical_size.x / desired_size.x;
+
+    let small_height_scale = desired_size.y / physical_size.y;
+    let small_width_scale = desired_size.x / physical_size.x;
+
+    let has_int_scale = desired_aspect_ratio.ratio() == physical_aspect_ratio.ratio()
+        && ((height_scale % 1. == 0. && width_scale % 1. == 0.)
+            || (small_height_scale % 1. == 0. && small_width_scale % 1. == 0.));
+
+    // Integer Scaling Exists
+    if has_int_scale {
+        return Ok(None);
+    }
+
+    let best_scale = if width_scale > height_scale {
+        height_scale
+    } else {
+        width_scale
+    };
+
+    let render_width = if best_scale >= 1. {
+        desired_size.x * best_scale.floor()
+    } else {
+        desired_size.x * best_scale
+    };
+
+    let render_height = if best_scale >= 1. {
+        desired_size.y * best_scale.floor()
+    } else {
+        desired_size.y * best_scale
+    };
+
+    let letterbox_size = physical_size.y - render_height;
+    let pillarbox_size = physical_size.x - render_width;
+
+    Ok(Some(Boxing {
+        boxing_offset: Vec2::new(pillarbox_size / 2., letterbox_size / 2.),
+        output_resolution: Vec2::new(render_width, render_height),
+    }))
+}
+/// Boxes `physical_size` to the Integer Scale of `desired_size` closest to the scale that fits
+/// inside it, clamping the boxed output to `physical_size` if that scale is too large.
+fn calculate_boxing_nearest(physical_size: &Vec2, desired_size: &Vec2) -> Result<Option<Boxing>> {
+    // We don't need the aspect ratios, but this ensures that neither size is degenerate.
+    AspectRatio::try_from(*desired_size)?;
+    AspectRatio::try_from(*physical_size)?;
+
+    let scale = (physical_size / desired_size).min_element().round().max(1.);
+    let output_resolution = (desired_size * scale).min(*physical_size);
+    if output_resolution == *physical_size {
+        return Ok(None);
+    }
+
+    Ok(Some(Boxing {
+        boxing_offset: ((physical_size - output_resolution) / 2.).floor(),
+        output_resolution,
+    }))
+}
+
+/// Checks if the next Integer Scale up (which has to be cropped) is closer to the area of the
+/// output than the largest Integer Scale which fits inside it.
+fn prefers_cropped_scale(physical_size: &Vec2, desired_size: &Vec2) -> bool {
+    let fit_scale = (physical_size / desired_size).min_element().floor();
+    if !fit_scale.is_finite() || fit_scale < 1. {
+        return false;
+    }
+
+    let physical_area = physical_size.x * physical_size.y;
+    let desired_area = desired_size.x * desired_size.y;
+    let area_difference = |scale: f32| (desired_area * scale * scale - physical_area).abs();
+
+    area_difference(fit_scale + 1.) < area_difference(fit_scale)
+}
+
+/// Returns true if the largest Integer Scale of `desired_size` that fits in `physical_size` covers
+/// less than `min_fraction` of its area. This is always false when `desired_size` doesn't fit.
+fn covers_too_little(physical_size: &Vec2, desired_size: &Vec2, min_fraction: f32) -> bool {
+    let fit_scale = (physical_size / desired_size).min_element().floor();
+    if !fit_scale.is_finite() || fit_scale < 1. {
+        return false;
+    }
+
+    let physical_area = physical_size.x * physical_size.y;
+    let content_area = desired_size.x * desired_size.y * fit_scale * fit_scale;
+    content_area / physical_area < min_fraction
+}
+
+fn calculate_boxing_downscaled(
+    physical_size: &Vec2,
+    desired_size: &Vec2,
+) -> Result<Option<Boxing>> {
+    // We don't need the aspect ratios, but this ensures that neither size is degenerate.
+    AspectRatio::try_from(*desired_size)?;
+    AspectRatio::try_from(*physical_size)?;
+
+    let best_divisor = (desired_size / physical_size).max_element().ceil().max(1.);
+    let output_resolution = desired_size / best_divisor;
+
+    // Integer Scaling Exists
+    if output_resolution == *physical_size {
+        return Ok(None);
+    }
+
+    Ok(Some(Boxing {
+        boxing_offset: (physical_size - output_resolution) / 2.,
+        output_resolution,
+    }))
+}
+
+fn calculate_boxing_independent_axes(
+    physical_size: &Vec2,
+    desired_size: &Vec2,
+) -> Result<Option<Boxing>> {
+    // We don't need the aspect ratios, but this ensures that neither size is degenerate.
+    AspectRatio::try_from(*desired_size)?;
+    AspectRatio::try_from(*physical_size)?;
+
+    let scale_axis = |physical: f32, desired: f32| {
+        if physical < desired {
+            desired / (desired / physical).ceil()
+        } else {
+            desired * (physical / desired).floor()
+        }
+    };
+
+    let output_resolution = Vec2::new(
+        scale_axis(physical_size.x, desired_size.x),
+        scale_axis(physical_size.y, desired_size.y),
+    );
+
+    // Integer Scaling Exists
+    if output_resolution == *physical_size {
+        return Ok(None);
+    }
+
+    Ok(Some(Boxing {
+        boxing_offset: (physical_size - output_resolution) / 2.,
+        output_resolution,
+    }))
+}
+
+fn calculate_boxing_perfect(physical_size: &Vec2, desired_size: &Vec2) -> Result<Option<Boxing>> {
+    let desired_aspect_ratio = AspectRatio::try_from(*desired_size)?;
+    let physical_aspect_ratio = AspectRatio::try_from(*physical_size)?;
+
+    let height_scale = physical_size.y / desired_size.y;
+    let width_scale = physical_size.x / desired_size.x;
+
+    let has_int_scale = desired_aspect_ratio.ratio() == physical_aspect_ratio.ratio()
+        && (height_scale % 1. == 0. && width_scale % 1. == 0.);
+
+    // Integer Scaling Exists
+    if has_int_scale {
+        return Ok(None);
+    }
+
+    if height_scale < 1. || width_scale < 1. {
+        let height_scale = desired_size.y / physical_size.y;
+        let width_scale = desired_size.x / physical_size.x;
+
+        // Recheck with the current values
+        let has_int_scale = desired_aspect_ratio.ratio() == physical_aspect_ratio.ratio()
+            && (height_scale % 1. == 0. && width_scale % 1. == 0.);
+
+        // Integer Scaling Exists
+        if has_int_scale {
+            return Ok(None);
+        }
+
+        let best_divisor = if height_scale < width_scale {
+            width_scale
+        } else {
+            height_scale
+        }
+        .ceil();
+
+        let render_height = desired_size.y / best_divisor;
+        let render_width = desired_size.x / best_divisor;
+
+        let letterbox_size = physical_size.y - render_height;
+        let pillarbox_size = physical_size.x - render_width;
+        Ok(Some(Boxing {
+            boxing_offset: Vec2::new(pillarbox_size / 2., letterbox_size / 2.),
+            output_resolution: Vec2::new(render_width, render_height),
+        }))
+    } else {
+        let best_scale = if width_scale > height_scale {
+            height_scale
+        } else {
+            width_scale
+        }
+        .floor();
+
+        let render_width = desired_size.x * best_scale;
+        let render_height = desired_size.y * best_scale;
+
+        let letterbox_size = physical_size.y - render_height;
+        let pillarbox_size = physical_size.x - render_width;
+        Ok(Some(Boxing {
+            boxing_offset: Vec2::new(pillarbox_size / 2., letterbox_size / 2.),
+            output_resolution: Vec2::new(render_width, render_height),
+        }))
+    }
+}
+
+/// Returns the part of a camera's render target that its viewport covers, as coordinates between
+/// 0 and 1 relative to the render target.
+///
+/// This works the same way for windows, images, and texture views. If the camera doesn't have a
+/// viewport then it covers the whole render target. This returns None if the render target can't
+/// be found, or has a size of zero.
+pub fn normalized_content_rect<'a>(
+    camera: &Camera,
+    target: &RenderTarget,
+    primary_window: Option<Entity>,
+    windows: impl IntoIterator<Item = (Entity, &'a Window)>,
+    images: &Assets<Image>,
+    texture_views: &ManualTextureViews,
+) -> Option<Rect> {
+    let target_size = target
+        .normalize(primary_window)?
+        .get_render_target_info(windows, images, texture_views)
+        .ok()?
+        .physical_size;
+    if target_size.cmpeq(UVec2::ZERO).any() {
+        return None;
+    }
+
+    let Some(viewport) = &camera.viewport else {
+        return Some(Rect::new(0., 0., 1., 1.));
+    };
+    let target_size = target_size.as_vec2();
+    Some(Rect::from_corners(
+        viewport.physical_position.as_vec2() / target_size,
+        (viewport.physical_position + viewport.physical_size).as_vec2() / target_size,
+    ))
+}
+
+/// Returns the largest integer scale of `base` that fits entirely within `target`.
+///
+/// This is the scale `CameraBox::ResolutionIntegerScale` uses when the target is larger than
+/// the base resolution. If `base` doesn't fit within `target` at all (or has a size of zero) then
+/// this returns 0.
+pub fn max_integer_scale(base: UVec2, target: UVec2) -> u32 {
+    if base.cmpeq(UVec2::ZERO).any() {
+        return 0;
+    }
+    (target / base).min_element()
+}
+
+/// Computes the boxing for `mode` within `rect`, rather than within a whole render target.
+///
+/// `rect` is in logical pixels and is converted to physical pixels with `scale_factor`, the
+/// returned `Boxing` is in physical pixels and is relative to the same origin as `rect`. If `mode`
+/// doesn't need to box within `rect` then the whole of `rect` is returned.
+///
+/// This returns None if `rect` is empty, or if `mode` can't be used within `rect` (such as a strict
+/// `CameraBox::StaticAspectRatio` that doesn't match it).
+pub fn compute_in_rect(mode: &CameraBox, rect: Rect, scale_factor: f32) -> Option<Boxing> {
+    let offset = (rect.min * scale_factor).round().max(Vec2::ZERO).as_uvec2();
+    let size = (rect.size() * scale_factor).round().as_uvec2();
+    if size.cmpeq(UVec2::ZERO).any() {
+        return None;
+    }
+
+    let physical_bars = physical_bars(mode, scale_factor);
+    let mode = physical_bars.as_ref().unwrap_or(mode);
+    match calculate_changes(mode, &size, &offset, &size, DEFAULT_ASPECT_EPSILON)? {
+        ViewportChanges::SetToNone => Some(Boxing {
+            boxing_offset: offset.as_vec2(),
+            output_resolution: size.as_vec2(),
+        }),
+        ViewportChanges::Box(boxing) => Some(boxing),
+        ViewportChanges::Degenerate | ViewportChanges::AspectMismatch { .. } => None,
+    }
+}
+
+/// Computes the viewport `camera_box` would give a camera rendering to each of `monitors` at their
+/// full resolution.
+///
+/// This is meant for previewing the boxing on every monitor (e.g. in a settings menu). The
+/// viewport is None for a monitor that wouldn't be boxed, or that `camera_box` can't be used on.
+pub fn box_per_monitor(
+    camera_box: &CameraBox,
+    monitors: &Query<(Entity, &Monitor)>,
+) -> Vec<(Entity, Option<Viewport>)> {
+    monitors
+        .iter()
+        .map(|(entity, monitor)| {
+            let size = UVec2::new(monitor.physical_width, monitor.physical_height);
+            if size.cmpeq(UVec2::ZERO).any() {
+                return (entity, None);
+            }
+
+            let viewport = match calculate_changes(
+                camera_box,
+                &size,
+                &UVec2::ZERO,
+                &size,
+                DEFAULT_ASPECT_EPSILON,
+            ) {
+                Some(ViewportChanges::Box(boxing)) => Some(Viewport {
+                    physical_position: boxing.boxing_offset.as_uvec2(),
+                    physical_size: boxing.output_resolution.as_uvec2(),
+                    ..Viewport::default()
+                }),
+                _ => None,
+            };
+            (entity, viewport)
+        })
+        .collect()
+}
+
+/// Returns the area of the render target `entity`'s camera currently draws to, in physical pixels.
+///
+/// This is meant for code that needs to work within the boxed output (e.g. converting the cursor's
+/// position to the world). It returns None if the camera doesn't exist or isn't boxed.
+///
+/// ```
+/// # use bevy_camera::Camera;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::URect;
+/// # use bevy_simple_screen_boxing::boxed_viewport;
+/// fn boxed_area(cameras: Query<(Entity, &Camera)>, all_cameras: Query<&Camera>) {
+///     for (entity, _) in cameras.iter() {
+///         if let Some(area) = boxed_viewport(entity, &all_cameras) {
+///             println!("{entity} is boxed to {area:?}");
+///         }
+///     }
+/// }
+/// ```
+pub fn boxed_viewport(entity: Entity, cameras: &Query<&Camera>) -> Option<URect> {
+    let viewport = cameras.get(entity).ok()?.viewport.as_ref()?;
+    Some(URect::from_corners(
+        viewport.physical_position,
+        viewport.physical_position + viewport.physical_size,
+    ))
+}
+
+/// Converts `window_position` (in logical pixels, like `Window::cursor_position`) to a position
+/// within `camera`'s viewport, by removing the boxing offset.
+///
+/// This returns None if the position is on one of the bars, which is useful for ignoring clicks on
+/// them. If the camera has no viewport, then `window_position` is returned as is.
+pub fn window_to_viewport(camera: &Camera, window_position: Vec2) -> Option<Vec2> {
+    let Some(viewport) = &camera.viewport else {
+        return Some(window_position);
+    };
+
+    let scale_factor = camera.target_scaling_factor().unwrap_or(1.);
+    let position = window_position - viewport.physical_position.as_vec2() / scale_factor;
+    let size = viewport.physical_size.as_vec2() / scale_factor;
+    (position.cmpge(Vec2::ZERO).all() && position.cmplt(size).all()).then_some(position)
+}
+
+/// Returns a stable hash of a viewport's position, size, and depth.
+///
+/// This is meant for checking that different machines computed the same viewport (e.g. in a
+/// lockstep game), so it is the same on every platform and won't change between versions of this
+/// crate. The boxing math only uses basic floating point operations (along with rounding), which
+/// are deterministic, so the same `CameraBox` and render target will always produce the same
+/// viewport, and the same digest.
+pub fn viewport_digest(viewport: &Viewport) -> u64 {
+    // 64-bit FNV-1a, over the little-endian bytes of each value.
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    [
+        viewport.physical_position.x,
+        viewport.physical_position.y,
+        viewport.physical_size.x,
+        viewport.physical_size.y,
+        viewport.depth.start.to_bits(),
+        viewport.depth.end.to_bits(),
+    ]
+    .iter()
+    .flat_map(|value| value.to_le_bytes())
+    .fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    })
+}
+
+/// A problem with a `CameraBox` configuration, returned by `validate`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ConfigWarning {
+    /// The resolution (or tile size and count, or world height) has a width or height of zero, so
+    /// nothing can be boxed to it.
+    ZeroResolution,
+
+    /// The resolution, aspect ratio, or world height has a width or height that is negative,
+    /// infinite, or NaN.
+    DegenerateAspectRatio,
+
+    /// The bars are at least as large as the given common resolution, so they will never fit.
+    /// Strict boxing will be disabled at this size, while non-strict boxing will be shrunk.
+    BarsDoNotFit { resolution: UVec2 },
+
+    /// The view index of a `CameraBox::XrViews` isn't less than its view count.
+    ViewIndexOutOfRange,
+}
+
+/// Common render target sizes that `validate` checks bars against.
+const COMMON_RESOLUTIONS: [UVec2; 4] = [
+    UVec2::new(1280, 720),
+    UVec2::new(1920, 1080),
+    UVec2::new(2560, 1440),
+    UVec2::new(3840, 2160),
+];
+
+/// Checks a `CameraBox` for configurations that will never box as expected.
+///
+/// This doesn't need a render target, so it's meant for giving feedback in tooling (like a
+/// settings menu) before the configuration is used. An empty list means nothing was found.
+pub fn validate(camera_box: &CameraBox) -> Vec<ConfigWarning> {
+    let mut warnings = Vec::new();
+
+    let bars_do_not_fit = |bars: UVec2| {
+        COMMON_RESOLUTIONS
+            .iter()
+            .filter(move |resolution| {
+                (bars.x > 0 && bars.x >= resolution.x) || (bars.y > 0 && bars.y >= resolution.y)
+            })
+            .map(|resolution| ConfigWarning::BarsDoNotFit {
+                resolution: *resolution,
+            })
+    };
+
+    match camera_box {
+        CameraBox::StaticResolution { resolution, .. } => {
+            if resolution.cmpeq(UVec2::ZERO).any() {
+                warnings.push(ConfigWarning::ZeroResolution);
+            }
+        }
+        CameraBox::StaticAspectRatio { aspect_ratio, .. } => {
+            if !aspect_ratio.ratio().is_finite() || aspect_ratio.ratio() <= 0. {
+                warnings.push(ConfigWarning::DegenerateAspectRatio);
+            }
+        }
+        CameraBox::ResolutionIntegerScale { resolution, .. } => {
+            if resolution.cmpeq(UVec2::ZERO).any() {
+                warnings.push(ConfigWarning::ZeroResolution);
+            }
+        }
+        CameraBox::FixedTiles {
+            tile_size,
+            cols,
+            rows,
+        } => {
+            if (tile_size.saturating_mul(UVec2::new(*cols, *rows)))
+                .cmpeq(UVec2::ZERO)
+                .any()
+            {
+                warnings.push(ConfigWarning::ZeroResolution);
+            }
+        }
+        CameraBox::IntegerAspect { base } => {
+            if base.cmpeq(UVec2::ZERO).any() {
+                warnings.push(ConfigWarning::ZeroResolution);
+            }
+        }
+        CameraBox::LetterBox { top, bottom, .. } => {
+            warnings.extend(bars_do_not_fit(UVec2::new(0, top.saturating_add(*bottom))));
+        }
+        CameraBox::PillarBox { left, right, .. } => {
+            warnings.extend(bars_do_not_fit(UVec2::new(left.saturating_add(*right), 0)));
+        }
+        CameraBox::WindowBox {
+            left,
+            right,
+            top,
+            bottom,
+            ..
+        } => warnings.extend(bars_do_not_fit(UVec2::new(
+            left.saturating_add(*right),
+            top.saturating_add(*bottom),
+        ))),
+        CameraBox::AspectRatioWithMargin {
+            aspect_ratio,
+            margin,
+        } => {
+            if !aspect_ratio.ratio().is_finite() || aspect_ratio.ratio() <= 0. {
+                warnings.push(ConfigWarning::DegenerateAspectRatio);
+            }
+            warnings.extend(bars_do_not_fit(
+                UVec2::new(margin.y, margin.x).saturating_add(UVec2::new(margin.w, margin.z)),
+            ));
+        }
+        CameraBox::FixedContentHeight { height: 0 } | CameraBox::FixedContentWidth { width: 0 } => {
+            warnings.push(ConfigWarning::ZeroResolution);
+        }
+        CameraBox::CutsceneLetterbox { video_size } => {
+            if video_size.cmpeq(UVec2::ZERO).any() {
+                warnings.push(ConfigWarning::ZeroResolution);
+            }
+        }
+        CameraBox::XrViews {
+            count,
+            view_index,
+            inner,
+        } => {
+            if view_index >= count {
+                warnings.push(ConfigWarning::ViewIndexOutOfRange);
+            }
+            warnings.extend(validate(inner));
+        }
+        CameraBox::ConstantVerticalExtent { world_height } => {
+            if *world_height == 0. {
+                warnings.push(ConfigWarning::ZeroResolution);
+            } else if !world_height.is_finite() || *world_height < 0. {
+                warnings.push(ConfigWarning::DegenerateAspectRatio);
+            }
+        }
+        CameraBox::FixedContentHeight { .. } | CameraBox::FixedContentWidth { .. } => (),
+    }
+
+    warnings
+}
+
+fn calculate_letterbox(physical_size: &Vec2, letterbox: (&u32, &u32)) -> Boxing {
+    // Summed as floats so bars too large for the render target can't overflow.
+    let letterbox_height = *letterbox.0 as f32 + *letterbox.1 as f32;
+    let render_width = physical_size.x;
+    let render_height = physical_size.y - letterbox_height;
+
+    Boxing {
+        boxing_offset: Vec2::new(0., *letterbox.0 as f32),
+        output_resolution: Vec2::new(render_width, render_height),
+    }
+}
+
+fn calculate_pillarbox(physical_size: &Vec2, pillarbox: (&u32, &u32)) -> Boxing {
+    let pillarbox_width = *pillarbox.0 as f32 + *pillarbox.1 as f32;
+    let render_height = physical_size.y;
+    let render_width = physical_size.x - pillarbox_width;
+
+    Boxing {
+        boxing_offset: Vec2::new(*pillarbox.0 as f32, 0.),
+        output_resolution: Vec2::new(render_width, render_height),
+    }
+}
+
+fn calculate_windowbox(physical_size: &Vec2, windowbox: [(&u32, &u32); 2]) -> Boxing {
+    let letterbox_height = *windowbox[0].0 as f32 + *windowbox[0].1 as f32;
+    let pillarbox_width = *windowbox[1].0 as f32 + *windowbox[1].1 as f32;
+
+    let render_height = physical_size.y - letterbox_height;
+    let render_width = physical_size.x - pillarbox_width;
+
+    Boxing {
+        boxing_offset: Vec2::new(*windowbox[1].0 as f32, *windowbox[0].0 as f32),
+        output_resolution: Vec2::new(render_width, render_height),
+    }
+}
+
+fn is_within_rect(rect: &UVec2, position: &UVec2, size: &UVec2) -> bool {
+    let actual_bounds = position.saturating_add(*size);
+    rect.x >= actual_bounds.x && rect.y >= actual_bounds.y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    impl Boxing {
+        fn new(boxing_offset: Vec2, output_resolution: Vec2) -> Self {
+            Boxing {
+                boxing_offset,
+                output_resolution,
+            }
+        }
+    }
+
+    mod internal {
+        use super::*;
+        use core::any::TypeId;
+
+        #[test]
+        #[cfg(debug_assertions)]
+        #[should_panic(expected = "has an empty size")]
+        fn test_viewport_assertion_empty() {
+            debug_assert_viewport(
+                &Boxing::new(Vec2::new(320., 0.), Vec2::new(0., 360.)),
+                UVec2::new(640, 360),
+                false,
+            );
+        }
+
+        #[test]
+        fn test_viewport_assertion_valid() {
+            debug_assert_viewport(
+                &Boxing::new(Vec2::new(0., 60.), Vec2::new(640., 240.)),
+                UVec2::new(640, 360),
+                false,
+            );
+            debug_assert_viewport(
+                &Boxing::new(Vec2::new(320., 180.), Vec2::ZERO),
+                UVec2::new(640, 360),
+                true,
+            );
+        }
+
+        #[test]
+        fn test_public_boxing_math() -> Result<()> {
+            let size = Vec2::new(640., 480.);
+            assert_eq!(
+                boxing::from_aspect_ratios(
+                    size,
+                    &AspectRatio::try_new(640., 480.)?,
+                    &AspectRatio::try_new(16., 9.)?
+                ),
+                calculate_boxing_from_aspect_ratios(
+                    &size,
+                    &AspectRatio::try_new(640., 480.)?,
+                    &AspectRatio::try_new(16., 9.)?
+                )
+            );
+            assert_eq!(
+                boxing::letterbox(size, 10, 20),
+                Boxing::new(Vec2::new(0., 10.), Vec2::new(640., 450.))
+            );
+            assert_eq!(
+                boxing::pillarbox(size, 10, 20),
+                Boxing::new(Vec2::new(10., 0.), Vec2::new(610., 480.))
+            );
+            assert_eq!(
+                boxing::windowbox(size, 10, 20, 30, 40),
+                Boxing::new(Vec2::new(30., 10.), Vec2::new(570., 450.))
+            );
+            let max = u32::MAX as f32;
+            assert_eq!(
+                boxing::letterbox(size, u32::MAX, 1),
+                Boxing::new(Vec2::new(0., max), Vec2::new(640., 480. - (max + 1.)))
+            );
+            assert_eq!(
+                boxing::pillarbox(size, 1, u32::MAX),
+                Boxing::new(Vec2::new(1., 0.), Vec2::new(640. - (1. + max), 480.))
+            );
+            assert_eq!(
+                boxing::windowbox(size, u32::MAX, u32::MAX, u32::MAX, u32::MAX),
+                Boxing::new(
+                    Vec2::new(max, max),
+                    Vec2::new(640. - (max + max), 480. - (max + max))
+                )
+            );
+            assert_eq!(
+                boxing::integer_scale_perfect(Vec2::new(1280., 720.), Vec2::new(640., 360.))?,
+                calculate_boxing_perfect(&Vec2::new(1280., 720.), &Vec2::new(640., 360.))?
+            );
+            assert_eq!(
+                boxing::integer_scale_imperfect(Vec2::new(1300., 720.), Vec2::new(640., 360.))?,
+                calculate_boxing_imperfect(&Vec2::new(1300., 720.), &Vec2::new(640., 360.))?
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_viewport_digest() -> Result<()> {
+            let digest = |mode: &CameraBox, rect: Rect| {
+                let boxing = compute_in_rect(mode, rect, 1.).unwrap();
+                viewport_digest(&Viewport {
+                    physical_position: boxing.boxing_offset.as_uvec2(),
+                    physical_size: boxing.output_resolution.as_uvec2(),
+                    ..Viewport::default()
+                })
+            };
+
+            assert_eq!(
+                digest(
+                    &CameraBox::StaticResolution {
+                        resolution: UVec2::new(640, 360),
+                        position: None,
+                        scale: ResolutionScaleMode::Exact,
+                    },
+                    Rect::new(0., 0., 1280., 720.)
+                ),
+                0xf971a6690bcb6742
+            );
+            assert_eq!(
+                digest(
+                    &CameraBox::LetterBox {
+                        top: 60,
+                        bottom: 60,
+                        strict_letterboxing: true,
+                        preserve_bar_ratio: false,
+                        logical_bars: false,
+                    },
+                    Rect::new(0., 0., 640., 360.)
+                ),
+                0x2474cbddf2d7291a
+            );
+            assert_eq!(
+                digest(
+                    &CameraBox::StaticAspectRatio {
+                        aspect_ratio: AspectRatio::try_new(16., 9.)?,
+                        position: None,
+                        strict: false,
+                    },
+                    Rect::new(0., 0., 1280., 720.)
+                ),
+                0x139cc6433bac7a15
+            );
+            assert_eq!(
+                digest(
+                    &CameraBox::StaticAspectRatio {
+                        aspect_ratio: AspectRatio::try_new(16., 9.)?,
+                        position: None,
+                        strict: false,
+                    },
+                    Rect::new(100., 50., 500., 450.)
+                ),
+                0x3977118826fa221b
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_target_aspect() -> Result<()> {
+            let window_size = UVec2::new(1280, 720);
+            let ratio = |camera_box: CameraBox| {
+                camera_box
+                    .target_aspect(window_size)
+                    .map(|aspect_ratio| aspect_ratio.ratio())
+            };
+
+            assert_eq!(
+                ratio(CameraBox::static_aspect_ratio(AspectRatio::FOUR_THREE)),
+                Some(4. / 3.)
+            );
+            assert_eq!(
+                ratio(CameraBox::AspectRatioWithMargin {
+                    aspect_ratio: AspectRatio::ULTRAWIDE,
+                    margin: UVec4::splat(10),
+                }),
+                Some(21. / 9.)
+            );
+            assert_eq!(
+                ratio(CameraBox::static_resolution(UVec2::new(640, 480))),
+                Some(4. / 3.)
+            );
+            assert_eq!(
+                ratio(CameraBox::static_resolution(UVec2::new(0, 480))),
+                None
+            );
+            assert_eq!(
+                ratio(CameraBox::ResolutionIntegerScale {
+                    resolution: UVec2::new(320, 240),
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
+                }),
+                Some(4. / 3.)
+            );
+            assert_eq!(
+                ratio(CameraBox::ResolutionIntegerScale {
+                    resolution: UVec2::new(300, 200),
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: true,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
+                }),
+                Some(1200. / 600.)
+            );
+            assert_eq!(
+                ratio(CameraBox::IntegerAspect {
+                    base: UVec2::new(384, 216),
+                }),
+                Some(16. / 9.)
+            );
+            assert_eq!(
+                ratio(CameraBox::FixedTiles {
+                    tile_size: UVec2::splat(16),
+                    cols: 20,
+                    rows: 15,
+                }),
+                Some(4. / 3.)
+            );
+            assert_eq!(
+                ratio(CameraBox::CutsceneLetterbox {
+                    video_size: UVec2::new(1920, 800),
+                }),
+                Some(1920. / 800.)
+            );
+            assert_eq!(ratio(CameraBox::letterbox(60, 60)), Some(1280. / 600.));
+            assert_eq!(ratio(CameraBox::pillarbox(160, 160)), Some(960. / 720.));
+            assert_eq!(
+                ratio(CameraBox::windowbox(10, 10, 40, 40)),
+                Some(1200. / 700.)
+            );
+            assert_eq!(
+                ratio(CameraBox::FixedContentHeight { height: 600 }),
+                Some(1280. / 600.)
+            );
+            assert_eq!(
+                ratio(CameraBox::FixedContentWidth { width: 960 }),
+                Some(960. / 720.)
+            );
+            assert_eq!(
+                ratio(CameraBox::ConstantVerticalExtent { world_height: 10. }),
+                Some(1280. / 720.)
+            );
+            assert_eq!(
+                ratio(CameraBox::XrViews {
+                    count: 2,
+                    view_index: 0,
+                    inner: Box::new(CameraBox::letterbox(0, 0)),
+                }),
+                Some(640. / 720.)
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_composite_transform() -> Result<()> {
+            let integer_scale = CameraBox::ResolutionIntegerScale {
+                resolution: UVec2::new(640, 360),
+                allow_imperfect_downscaled_boxing: false,
+                independent_axis_scale: false,
+                fit: IntegerScaleFit::FitInside,
+                force_downscale: false,
+                min_content_fraction: None,
+            };
+            assert_eq!(
+                integer_scale.composite_transform(UVec2::new(1920, 1200), 1.),
+                (Vec2::new(1., 0.9), Vec2::ZERO)
+            );
+            assert_eq!(
+                integer_scale.composite_transform(UVec2::new(1280, 720), 1.),
+                (Vec2::ONE, Vec2::ZERO)
+            );
+
+            let aspect_ratio = CameraBox::StaticAspectRatio {
+                aspect_ratio: AspectRatio::try_new(4., 3.)?,
+                position: Some(UVec2::ZERO),
+                strict: false,
+            };
+            assert_eq!(
+                aspect_ratio.composite_transform(UVec2::new(1280, 720), 1.),
+                (Vec2::new(0.75, 1.), Vec2::new(-160., 0.))
+            );
+            assert_eq!(
+                aspect_ratio.composite_transform(UVec2::new(1280, 720), 2.),
+                (Vec2::new(0.75, 1.), Vec2::new(-80., 0.))
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_xr_views() -> Result<()> {
+            let square = AspectRatio::try_new(1., 1.)?;
+            let view = |view_index| CameraBox::XrViews {
+                count: 2,
+                view_index,
+                inner: Box::new(CameraBox::static_aspect_ratio(square)),
+            };
+            let target = Rect::new(0., 0., 1280., 480.);
+
+            assert_eq!(
+                compute_in_rect(&view(0), target, 1.),
+                Some(Boxing::new(Vec2::new(80., 0.), Vec2::new(480., 480.)))
+            );
+            assert_eq!(
+                compute_in_rect(&view(1), target, 1.),
+                Some(Boxing::new(Vec2::new(720., 0.), Vec2::new(480., 480.)))
+            );
+
+            // If the inner boxing fills the view, then the whole column is used.
+            let column = CameraBox::XrViews {
+                count: 2,
+                view_index: 1,
+                inner: Box::new(CameraBox::static_aspect_ratio(AspectRatio::try_new(
+                    640., 480.,
+                )?)),
+            };
+            assert_eq!(
+                compute_in_rect(&column, target, 1.),
+                Some(Boxing::new(Vec2::new(640., 0.), Vec2::new(640., 480.)))
+            );
+
+            assert_eq!(
+                compute_in_rect(&view(2), target, 1.),
+                Some(Boxing::new(Vec2::ZERO, Vec2::new(1280., 480.)))
+            );
+            assert_eq!(validate(&view(2)), vec![ConfigWarning::ViewIndexOutOfRange]);
+            Ok(())
+        }
+
+        #[test]
+        fn test_compute_in_rect() -> Result<()> {
+            let widescreen = CameraBox::StaticAspectRatio {
+                aspect_ratio: AspectRatio::try_new(16., 9.)?,
+                position: None,
+                strict: false,
+            };
+
+            assert_eq!(
+                compute_in_rect(
+                    &widescreen,
+                    Rect::from_corners(Vec2::new(100., 50.), Vec2::new(500., 450.)),
+                    1.
+                ),
+                Some(Boxing::new(Vec2::new(100., 137.), Vec2::new(400., 225.)))
+            );
+
+            assert_eq!(
+                compute_in_rect(
+                    &widescreen,
+                    Rect::from_corners(Vec2::new(50., 25.), Vec2::new(250., 225.)),
+                    2.
+                ),
+                Some(Boxing::new(Vec2::new(100., 137.), Vec2::new(400., 225.)))
+            );
+
+            assert_eq!(
+                compute_in_rect(
+                    &widescreen,
+                    Rect::from_corners(Vec2::new(10., 20.), Vec2::new(650., 380.)),
+                    1.
+                ),
+                Some(Boxing::new(Vec2::new(10., 20.), Vec2::new(640., 360.)))
+            );
+
+            assert_eq!(
+                compute_in_rect(
+                    &CameraBox::LetterBox {
+                        top: 20,
+                        bottom: 20,
+                        strict_letterboxing: true,
+                        preserve_bar_ratio: false,
+                        logical_bars: false,
+                    },
+                    Rect::from_corners(Vec2::new(640., 0.), Vec2::new(1280., 360.)),
+                    1.
+                ),
+                Some(Boxing::new(Vec2::new(640., 20.), Vec2::new(640., 320.)))
+            );
+
+            assert_eq!(
+                compute_in_rect(&widescreen, Rect::new(0., 0., 0., 360.), 1.),
+                None
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_aspect_ratio_scaling() -> Result<()> {
+            assert_eq!(
+                calculate_boxing_from_aspect_ratios(
+                    &Vec2::new(640., 360.),
+                    &AspectRatio::try_new(640., 360.)?,
+                    &AspectRatio::try_new(640., 360.)?
+                ),
+                Boxing::new(Vec2::ZERO, Vec2::new(640., 360.))
+            );
+
+            assert_eq!(
+                calculate_boxing_from_aspect_ratios(
+                    &Vec2::new(1280., 720.),
+                    &AspectRatio::try_new(1280., 720.)?,
+                    &AspectRatio::try_new(640., 360.)?
+                ),
+                Boxing::new(Vec2::ZERO, Vec2::new(1280., 720.))
+            );
+
+            assert_eq!(
+                calculate_boxing_from_aspect_ratios(
+                    &Vec2::new(1920., 1080.),
+                    &AspectRatio::try_new(1920., 1080.)?,
+                    &AspectRatio::try_new(1280., 720.)?
+                ),
+                Boxing::new(Vec2::ZERO, Vec2::new(1920., 1080.))
+            );
+
+            assert_eq!(
+                calculate_boxing_from_aspect_ratios(
+                    &Vec2::new(640., 480.),
+                    &AspectRatio::try_new(640., 480.)?,
+                    &AspectRatio::try_new(640., 360.)?
+                ),
+                Boxing::new(Vec2::new(0., 60.), Vec2::new(640., 360.))
+            );
+
+            assert_eq!(
+                calculate_boxing_from_aspect_ratios(
+                    &Vec2::new(640., 360.),
+                    &AspectRatio::try_new(640., 360.)?,
+                    &AspectRatio::try_new(640., 480.)?
+                ),
+                Boxing::new(Vec2::new(80., 0.), Vec2::new(480., 360.))
+            );
+
+            assert_eq!(
+                calculate_boxing_from_aspect_ratios(
+                    &Vec2::new(480., 640.),
+                    &AspectRatio::try_new(480., 640.)?,
+                    &AspectRatio::try_new(1280., 720.)?
+                ),
+                Boxing::new(Vec2::new(0., 185.), Vec2::new(480., 270.))
+            );
+
+            assert_eq!(
+                calculate_boxing_from_aspect_ratios(
+                    &Vec2::new(1280., 720.),
+                    &AspectRatio::try_new(1280., 720.)?,
+                    &AspectRatio::try_new(480., 640.)?
+                ),
+                Boxing::new(Vec2::new(370., 0.), Vec2::new(540., 720.))
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_calculate_boxing_imperfect() {
+            assert!(
+                calculate_boxing_imperfect(&Vec2::new(640., 360.), &Vec2::new(640., 360.))
+                    .is_ok_and(|u| u.is_none()),
+                "Testing against the same resolution failed! (360p -> 360p)",
+            );
+
+            // Test Output with Expected Boxing
+            assert!(
+                calculate_boxing_imperfect(&Vec2::new(1920., 1080.), &Vec2::new(1280., 720.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(
+                        |u| u == Boxing::new(Vec2::new(320., 180.), Vec2::new(1280., 720.))
+                    ),
+                "Testing against a non-integer (but square) scaling failed! (720p -> 1080p)"
+            );
+
+            // Test Output to imperfect scale
+            assert!(
+                calculate_boxing_imperfect(&Vec2::new(3840., 2160.), &Vec2::new(1920., 1080.))
+                    .is_ok_and(|u| u.is_none()),
+                "Testing against an integer scale resolution failed! (1080p -> 2160p)"
+            );
+
+            assert!(
+                calculate_boxing_imperfect(&Vec2::new(1280., 722.), &Vec2::new(640., 360.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(|u| u == Boxing::new(Vec2::new(0., 1.), Vec2::new(1280., 720.))),
+                "Testing against minor increase to height in scaling failed! (360p -> 1280x722)"
+            );
+
+            assert!(
+                calculate_boxing_imperfect(&Vec2::new(1282., 720.), &Vec2::new(640., 360.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(|u| u == Boxing::new(Vec2::new(1., 0.), Vec2::new(1280., 720.))),
+                "Testing against minor increase to width in scaling failed! (360p -> 1282x720)"
+            );
+
+            assert!(
+                calculate_boxing_imperfect(&Vec2::new(320., 180.), &Vec2::new(640., 360.))
+                    .is_ok_and(|u| u.is_none()),
+                "Testing against downscaling failed! (360p -> 180p)"
+            );
+
+            assert!(
+                calculate_boxing_imperfect(&Vec2::new(330., 190.), &Vec2::new(640., 360.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(
+                        |u| u == Boxing::new(Vec2::new(0., 2.1875), Vec2::new(330., 185.625))
+                    ),
+                "Testing against off downscaling failed! (360p -> (180p + 10))"
+            );
+
+            assert!(
+                calculate_boxing_imperfect(&Vec2::new(320., 620.), &Vec2::new(320., 620.))
+                    .is_ok_and(|u| u.is_none()),
+                "Testing against Vertical Resolutions failed! (320x620 -> 320x620)"
+            );
+
+            assert!(
+                calculate_boxing_imperfect(&Vec2::new(320., 620.), &Vec2::new(640., 360.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(|u| u == Boxing::new(Vec2::new(0., 220.), Vec2::new(320., 180.))),
+                "Testing against Vertical Output to Widescreen Input failed! (360p -> 320x620)"
+            );
+
+            assert!(
+                calculate_boxing_imperfect(&Vec2::new(1280., 720.), &Vec2::new(640., 480.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(|u| u == Boxing::new(Vec2::new(320., 120.), Vec2::new(640., 480.))),
+                "Testing against 4:3 480p -> 16:9 720p failed!"
+            );
+        }
+
+        #[test]
+        fn test_calculate_boxing_downscaled() {
+            assert!(
+                calculate_boxing_downscaled(&Vec2::new(640., 360.), &Vec2::new(1280., 720.))
+                    .is_ok_and(|u| u.is_none()),
+                "Testing against an integer divisor failed! (720p -> 360p)"
+            );
+
+            assert!(
+                calculate_boxing_downscaled(&Vec2::new(700., 400.), &Vec2::new(1280., 720.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(|u| u == Boxing::new(Vec2::new(30., 20.), Vec2::new(640., 360.))),
+                "Testing against a non-integer divisor failed! (720p -> 700x400)"
+            );
+
+            assert!(
+                calculate_boxing_downscaled(&Vec2::new(400., 400.), &Vec2::new(1280., 720.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(|u| u == Boxing::new(Vec2::new(40., 110.), Vec2::new(320., 180.))),
+                "Testing against a divisor of 4 failed! (720p -> 400x400)"
+            );
+
+            // We never scale up, even if it would fit.
+            assert!(
+                calculate_boxing_downscaled(&Vec2::new(1920., 1080.), &Vec2::new(640., 360.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(|u| u
+                        == Boxing::new(Vec2::new(640., 360.), Vec2::new(640., 360.))),
+                "Testing against a larger output failed! (360p -> 1080p)"
+            );
+        }
+
+        #[test]
+        fn test_calculate_boxing_independent_axes() {
+            assert!(
+                calculate_boxing_independent_axes(&Vec2::new(1280., 720.), &Vec2::new(320., 180.))
+                    .is_ok_and(|u| u.is_none()),
+                "Testing against an integer scale resolution failed! (180p -> 720p)"
+            );
+
+            // Both axes scale by 4, but leave different amounts of space behind.
+            assert!(
+                calculate_boxing_independent_axes(&Vec2::new(1280., 800.), &Vec2::new(320., 180.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(|u| u == Boxing::new(Vec2::new(0., 40.), Vec2::new(1280., 720.))),
+                "Testing against a taller output failed! (180p -> 1280x800)"
+            );
+
+            // Each axis scales differently (5x wide, 4x tall), so the pixels are rectangular.
+            assert!(
+                calculate_boxing_independent_axes(&Vec2::new(1700., 720.), &Vec2::new(320., 180.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(|u| u == Boxing::new(Vec2::new(50., 0.), Vec2::new(1600., 720.))),
+                "Testing against differing axis scales failed! (180p -> 1700x720)"
+            );
+
+            assert!(
+                calculate_boxing_independent_axes(&Vec2::new(1280., 720.), &Vec2::new(0., 180.))
+                    .is_err(),
+                "Testing against a degenerate resolution failed!"
+            );
+        }
+
+        #[test]
+        fn test_calculate_boxing_perfect() {
+            assert!(
+                calculate_boxing_perfect(&Vec2::new(640., 360.), &Vec2::new(640., 360.))
+                    .is_ok_and(|u| u.is_none()),
+                "Testing against the same resolution failed! (360p -> 360p)",
+            );
+
+            // Test Output with Expected Boxing
+            assert!(
+                calculate_boxing_perfect(&Vec2::new(1920., 1080.), &Vec2::new(1280., 720.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(
+                        |u| u == Boxing::new(Vec2::new(320., 180.), Vec2::new(1280., 720.))
+                    ),
+                "Testing against a non-integer (but square) scaling failed! (720p -> 1080p)"
+            );
+
+            // Test Output to perfect scale
+            assert!(
+                calculate_boxing_perfect(&Vec2::new(3840., 2160.), &Vec2::new(1920., 1080.))
+                    .is_ok_and(|u| u.is_none()),
+                "Testing against an integer scale resolution failed! (1080p -> 2160p)"
+            );
+
+            assert!(
+                calculate_boxing_perfect(&Vec2::new(1280., 722.), &Vec2::new(640., 360.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(|u| u == Boxing::new(Vec2::new(0., 1.), Vec2::new(1280., 720.))),
+                "Testing against minor increase to height in scaling failed! (360p -> 1280x722)"
+            );
+
+            assert!(
+                calculate_boxing_perfect(&Vec2::new(1282., 720.), &Vec2::new(640., 360.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(|u| u == Boxing::new(Vec2::new(1., 0.), Vec2::new(1280., 720.))),
+                "Testing against minor increase to width in scaling failed! (360p -> 1282x720)"
+            );
+
+            assert!(
+                calculate_boxing_perfect(&Vec2::new(320., 180.), &Vec2::new(640., 360.))
+                    .is_ok_and(|u| u.is_none()),
+                "Testing against downscaling failed! (360p -> 180p)"
+            );
+
+            assert!(
+                calculate_boxing_perfect(&Vec2::new(330., 190.), &Vec2::new(640., 360.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(|u| u == Boxing::new(Vec2::new(5., 5.), Vec2::new(320., 180.))),
+                "Testing against off downscaling failed! (360p -> (180p + 10))"
+            );
+
+            assert!(
+                calculate_boxing_perfect(&Vec2::new(320., 620.), &Vec2::new(320., 620.))
+                    .is_ok_and(|u| u.is_none()),
+                "Testing against Vertical Resolutions failed! (320x620 -> 320x620)"
+            );
+
+            assert!(
+                calculate_boxing_perfect(&Vec2::new(320., 620.), &Vec2::new(640., 360.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(|u| u == Boxing::new(Vec2::new(0., 220.), Vec2::new(320., 180.))),
+                "Testing against Vertical Output to Widescreen Input failed! (360p -> 320x620)"
+            );
+
+            assert!(
+                calculate_boxing_perfect(&Vec2::new(1280., 720.), &Vec2::new(640., 480.))
+                    .ok()
+                    .flatten()
+                    .is_some_and(|u| u == Boxing::new(Vec2::new(320., 120.), Vec2::new(640., 480.))),
+                "Testing against 4:3 480p -> 16:9 720p failed!"
+            );
+        }
+
+        #[test]
+        fn test_plugin_registers_types() {
+            let mut app = App::new();
+            app.add_plugins(CameraBoxingPlugin::default());
+
+            let registry = app.world().resource::<AppTypeRegistry>().read();
+            assert!(registry.contains(TypeId::of::<CameraBox>()));
+            assert!(registry.contains(TypeId::of::<ComputedBoxing>()));
+            assert!(registry.contains(TypeId::of::<CameraBoxingSettings>()));
+            assert!(registry.contains(TypeId::of::<NoBoxBehavior>()));
+            assert!(registry.contains(TypeId::of::<PendingTargetSize>()));
+            assert!(registry.contains(TypeId::of::<TemporaryBox>()));
+            assert!(registry.contains(TypeId::of::<NestedWithin>()));
+            assert!(registry.contains(TypeId::of::<HasNested>()));
+            assert!(registry.contains(TypeId::of::<BoxingDebugSnapshot>()));
+            assert!(registry.contains(TypeId::of::<CameraBoxDebugEntry>()));
+        }
+
+        #[test]
+        fn test_validate() -> Result<()> {
+            assert_eq!(
+                validate(&CameraBox::StaticResolution {
+                    resolution: UVec2::new(0, 360),
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                }),
+                vec![ConfigWarning::ZeroResolution]
+            );
+            assert_eq!(
+                validate(&CameraBox::ResolutionIntegerScale {
+                    resolution: UVec2::new(640, 0),
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
+                }),
+                vec![ConfigWarning::ZeroResolution]
+            );
+            assert_eq!(
+                validate(&CameraBox::LetterBox {
+                    top: 500,
+                    bottom: 500,
+                    strict_letterboxing: true,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                }),
+                vec![
+                    ConfigWarning::BarsDoNotFit {
+                        resolution: UVec2::new(1280, 720)
+                    },
+                    ConfigWarning::BarsDoNotFit {
+                        resolution: UVec2::new(1920, 1080)
+                    },
+                ]
+            );
+
+            assert!(
+                validate(&CameraBox::StaticResolution {
+                    resolution: UVec2::new(640, 360),
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                })
+                .is_empty()
+            );
+            assert!(
+                validate(&CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::try_new(16., 9.)?,
+                    position: None,
+                    strict: false,
+                })
+                .is_empty()
+            );
+            assert!(
+                validate(&CameraBox::WindowBox {
+                    left: 10,
+                    right: 10,
+                    top: 10,
+                    bottom: 10,
+                    strict_windowboxing: true,
+                })
+                .is_empty()
+            );
+
+            // Extreme values (like from a settings menu) are reported instead of overflowing.
+            let bars_do_not_fit = COMMON_RESOLUTIONS
+                .iter()
+                .map(|resolution| ConfigWarning::BarsDoNotFit {
+                    resolution: *resolution,
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                validate(&CameraBox::letterbox(u32::MAX, u32::MAX)),
+                bars_do_not_fit
+            );
+            assert_eq!(
+                validate(&CameraBox::windowbox(u32::MAX, 1, 1, u32::MAX)),
+                bars_do_not_fit
+            );
+            assert_eq!(
+                validate(&CameraBox::AspectRatioWithMargin {
+                    aspect_ratio: AspectRatio::SIXTEEN_NINE,
+                    margin: UVec4::splat(u32::MAX),
+                }),
+                bars_do_not_fit
+            );
+            let huge_tiles = CameraBox::FixedTiles {
+                tile_size: UVec2::splat(u32::MAX),
+                cols: 2,
+                rows: 2,
+            };
+            assert!(validate(&huge_tiles).is_empty());
+            let target = UVec2::new(1280, 720);
+            assert!(huge_tiles.target_aspect(target).is_some());
+            assert!(
+                calculate_changes(
+                    &huge_tiles,
+                    &target,
+                    &UVec2::ZERO,
+                    &target,
+                    DEFAULT_ASPECT_EPSILON
+                )
+                .is_some()
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_boxed_axes() {
+            let computed = |boxing_offset: Vec2, output_resolution: Vec2| ComputedBoxing {
+                target_size: UVec2::new(1280, 720),
+                boxing_offset,
+                output_resolution,
+                remainder: Vec2::new(1280., 720.) - output_resolution,
+                used_imperfect_scaling: false,
+            };
+
+            // Letterboxing shrinks the output vertically.
+            assert_eq!(
+                computed(Vec2::new(0., 60.), Vec2::new(1280., 600.)).boxed_axes(),
+                BoxedAxes::Vertical
+            );
+            // Pillarboxing shrinks the output horizontally.
+            assert_eq!(
+                computed(Vec2::new(160., 0.), Vec2::new(960., 720.)).boxed_axes(),
+                BoxedAxes::Horizontal
+            );
+            assert_eq!(
+                computed(Vec2::new(320., 180.), Vec2::new(640., 360.)).boxed_axes(),
+                BoxedAxes::Both
+            );
+            assert_eq!(
+                computed(Vec2::ZERO, Vec2::new(1280., 720.)).boxed_axes(),
+                BoxedAxes::None
+            );
+        }
+
+        #[test]
+        fn test_boxing_lerp() {
+            let from = Boxing {
+                boxing_offset: Vec2::ZERO,
+                output_resolution: Vec2::new(1280., 720.),
+            };
+            let to = Boxing {
+                boxing_offset: Vec2::new(160., 0.),
+                output_resolution: Vec2::new(960., 720.),
+            };
+
+            assert_eq!(from.lerp(&to, 0.), from);
+            assert_eq!(
+                from.lerp(&to, 0.5),
+                Boxing {
+                    boxing_offset: Vec2::new(80., 0.),
+                    output_resolution: Vec2::new(1120., 720.),
+                }
+            );
+            assert_eq!(from.lerp(&to, 1.), to);
+            assert_eq!(from.lerp(&to, -1.), from);
+            assert_eq!(from.lerp(&to, 2.), to);
+        }
+
+        #[test]
+        fn test_max_integer_scale() {
+            assert_eq!(
+                max_integer_scale(UVec2::new(640, 360), UVec2::new(640, 360)),
+                1
+            );
+            assert_eq!(
+                max_integer_scale(UVec2::new(640, 360), UVec2::new(1280, 720)),
+                2
+            );
+            assert_eq!(
+                max_integer_scale(UVec2::new(640, 360), UVec2::new(1920, 1080)),
+                3
+            );
+            assert_eq!(
+                max_integer_scale(UVec2::new(320, 180), UVec2::new(2560, 1440)),
+                8
+            );
+            assert_eq!(
+                max_integer_scale(UVec2::new(320, 180), UVec2::new(1000, 600)),
+                3
+            );
+            assert_eq!(
+                max_integer_scale(UVec2::new(640, 480), UVec2::new(1280, 720)),
+                1
+            );
+            assert_eq!(
+                max_integer_scale(UVec2::new(640, 360), UVec2::new(320, 180)),
+                0
+            );
+            assert_eq!(
+                max_integer_scale(UVec2::new(640, 360), UVec2::new(1280, 300)),
+                0
+            );
+            assert_eq!(
+                max_integer_scale(UVec2::new(0, 360), UVec2::new(1280, 720)),
+                0
+            );
+        }
+
+        #[test]
+        fn test_calculate_letterbox() {
+            let inputs: [(u32, u32); 6] =
+                [(100, 100), (100, 0), (100, 50), (50, 100), (0, 0), (0, 100)];
+            let physical_size = Vec2::new(640., 360.);
+            let outputs: [_; 6] = [
+                Boxing::new(Vec2::new(0., 100.), Vec2::new(640., 160.)),
+                Boxing::new(Vec2::new(0., 100.), Vec2::new(640., 260.)),
+                Boxing::new(Vec2::new(0., 100.), Vec2::new(640., 210.)),
+                Boxing::new(Vec2::new(0., 50.), Vec2::new(640., 210.)),
+                Boxing::new(Vec2::new(0., 0.), Vec2::new(640., 360.)),
+                Boxing::new(Vec2::new(0., 0.), Vec2::new(640., 260.)),
+            ];
+            for (i, input) in inputs.iter().enumerate() {
+                assert_eq!(
+                    calculate_letterbox(&physical_size, (&input.0, &input.1)),
+                    outputs[i]
+                );
+            }
+        }
+        #[test]
+        fn test_calculate_pillarbox() {
+            let inputs: [(u32, u32); 6] =
+                [(100, 100), (100, 0), (100, 50), (50, 100), (0, 0), (0, 100)];
+            let physical_size = Vec2::new(640., 360.);
+            let outputs = [
+                Boxing::new(Vec2::new(100., 0.), Vec2::new(440., 360.)),
+                Boxing::new(Vec2::new(100., 0.), Vec2::new(540., 360.)),
+                Boxing::new(Vec2::new(100., 0.), Vec2::new(490., 360.)),
+                Boxing::new(Vec2::new(50., 0.), Vec2::new(490., 360.)),
+                Boxing::new(Vec2::new(0., 0.), Vec2::new(640., 360.)),
+                Boxing::new(Vec2::new(0., 0.), Vec2::new(540., 360.)),
+            ];
+            for (i, input) in inputs.iter().enumerate() {
+                assert_eq!(
+                    calculate_pillarbox(&physical_size, (&input.0, &input.1)),
+                    outputs[i]
+                );
+            }
+        }
+
+        #[test]
+        fn test_content_uv_rect() {
+            let centered = ComputedBoxing {
+                target_size: UVec2::new(1280, 720),
+                boxing_offset: Vec2::new(320., 180.),
+                output_resolution: Vec2::new(640., 360.),
+                remainder: Vec2::new(640., 360.),
+                used_imperfect_scaling: false,
+            };
+            assert_eq!(
+                centered.content_uv_rect(UVec2::new(1280, 720)),
+                Rect::new(0.25, 0.25, 0.75, 0.75)
+            );
+
+            let off_center = ComputedBoxing {
+                target_size: UVec2::new(1280, 720),
+                boxing_offset: Vec2::new(0., 360.),
+                output_resolution: Vec2::new(640., 360.),
+                remainder: Vec2::new(640., 360.),
+                used_imperfect_scaling: false,
+            };
+            assert_eq!(
+                off_center.content_uv_rect(UVec2::new(1280, 720)),
+                Rect::new(0., 0.5, 0.5, 1.)
+            );
+
+            let unboxed = ComputedBoxing {
+                target_size: UVec2::new(1280, 720),
+                boxing_offset: Vec2::ZERO,
+                output_resolution: Vec2::new(1280., 720.),
+                remainder: Vec2::ZERO,
+                used_imperfect_scaling: false,
+            };
+            assert_eq!(
+                unboxed.content_uv_rect(UVec2::new(1280, 720)),
+                Rect::new(0., 0., 1., 1.)
+            );
+        }
+
+        #[test]
+        fn test_calculate_windowbox() {
+            let inputs: [[(&u32, &u32); 2]; 8] = [
+                [(&0, &0), (&0, &0)],     //Test Noboxing
+                [(&100, &100), (&0, &0)], //Test Letterboxing
+                [(&0, &0), (&100, &100)], //Test Pillarboxing
+                [(&50, &0), (&50, &0)],   //Test Boxing Bottom Left
+                [(&0, &50), (&0, &50)],   //Test Bottom Boxing.
+                [(&50, &50), (&50, &50)], //Test Full Boxing
+                [(&50, &0), (&0, &50)],   //Test Opp Boxing
+                [(&0, &50), (&50, &0)],   //Test Opp Boxing 2
+            ];
+            let physical_size = Vec2::new(640., 360.);
+
+            let outputs: [Boxing; 8] = [
+                Boxing::new(Vec2::new(0., 0.), physical_size),
+                Boxing::new(Vec2::new(0., 100.), Vec2::new(640., 160.)),
+                Boxing::new(Vec2::new(100., 0.), Vec2::new(440., 360.)),
+                Boxing::new(Vec2::new(50., 50.), Vec2::new(590., 310.)),
+                Boxing::new(Vec2::new(0., 0.), Vec2::new(590., 310.)),
+                Boxing::new(Vec2::new(50., 50.), Vec2::new(540., 260.)),
+                Boxing::new(Vec2::new(0., 50.), Vec2::new(590., 310.)),
+                Boxing::new(Vec2::new(50., 0.), Vec2::new(590., 310.)),
+            ];
+
+            for (i, input) in inputs.into_iter().enumerate() {
+                assert_eq!(calculate_windowbox(&physical_size, input), outputs[i],);
+            }
+        }
+
+        /// One of every `CameraBox` variant, for round-trip tests.
+        fn every_camera_box() -> Result<Vec<CameraBox>> {
+            Ok(vec![
+                CameraBox::StaticResolution {
+                    resolution: UVec2::new(640, 360),
+                    position: Some(UVec2::new(10, 20)),
+                    scale: ResolutionScaleMode::IntegerFit,
+                },
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::try_new(640., 480.)?,
+                    position: None,
+                    strict: true,
+                },
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::try_new(1920., 1080.)?,
+                    position: Some(UVec2::new(1, 2)),
+                    strict: false,
+                },
+                CameraBox::ResolutionIntegerScale {
+                    resolution: UVec2::new(320, 180),
+                    allow_imperfect_downscaled_boxing: true,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::AreaMatch,
+                    force_downscale: true,
+                    min_content_fraction: Some(0.6),
+                },
+                CameraBox::FixedTiles {
+                    tile_size: UVec2::new(16, 16),
+                    cols: 20,
+                    rows: 12,
+                },
+                CameraBox::IntegerAspect {
+                    base: UVec2::new(384, 216),
+                },
+                CameraBox::LetterBox {
+                    top: 10,
+                    bottom: 20,
+                    strict_letterboxing: true,
+                    preserve_bar_ratio: true,
+                    logical_bars: false,
+                },
+                CameraBox::pillarbox(30, 40),
+                CameraBox::windowbox(1, 2, 3, 4),
+                CameraBox::AspectRatioWithMargin {
+                    aspect_ratio: AspectRatio::try_new(21., 9.)?,
+                    margin: UVec4::new(1, 2, 3, 4),
+                },
+                CameraBox::FixedContentHeight { height: 720 },
+                CameraBox::FixedContentWidth { width: 1280 },
+                CameraBox::ConstantVerticalExtent { world_height: 10. },
+                CameraBox::CutsceneLetterbox {
+                    video_size: UVec2::new(1920, 800),
+                },
+                CameraBox::XrViews {
+                    count: 2,
+                    view_index: 1,
+                    inner: Box::new(CameraBox::letterbox(5, 5)),
+                },
+            ])
+        }
+
+        #[test]
+        #[cfg(feature = "serde")]
+        fn test_camera_box_serde() -> Result<()> {
+            for camera_box in every_camera_box()? {
+                let serialized = ron::to_string(&camera_box)?;
+                assert_eq!(ron::from_str::<CameraBox>(&serialized)?, camera_box);
+            }
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_camera_box_reflect_serialization() -> Result<()> {
+            use bevy_reflect::serde::{ReflectDeserializer, ReflectSerializer};
+            use bevy_reflect::{FromReflect, TypeRegistry};
+            use serde::de::DeserializeSeed;
+
+            // Scenes (de)serialize components the same way, through the type registry.
+            let mut registry = TypeRegistry::default();
+            registry.register::<CameraBox>();
+            for camera_box in every_camera_box()? {
+                let serialized = ron::to_string(&ReflectSerializer::new(&camera_box, &registry))?;
+                let mut deserializer = ron::Deserializer::from_str(&serialized)?;
+                let deserialized =
+                    ReflectDeserializer::new(&registry).deserialize(&mut deserializer)?;
+                assert_eq!(
+                    CameraBox::from_reflect(deserialized.as_partial_reflect()),
+                    Some(camera_box)
+                );
+            }
+
+            Ok(())
+        }
+    }
+
+    mod systems {
+        use super::*;
+        use bevy_asset::AssetId;
+        use bevy_camera::{RenderTarget, SubCameraView};
+        use bevy_render::render_resource::Extent3d;
+        use bevy_window::{MonitorSelection, WindowRef, WindowResolution};
+
+        const W360P: UVec2 = UVec2::new(640, 360);
+        const W720P: UVec2 = UVec2::new(1280, 720);
+        const W180P: UVec2 = UVec2::new(320, 180);
+
+        fn setup_app(camerabox: CameraBox, window_resolution: WindowResolution) -> (App, Entity) {
+            let mut app = App::new();
+
+            app.init_resource::<ManualTextureViews>();
+            app.init_resource::<Assets<Image>>();
+            app.init_resource::<CameraBoxingSettings>();
+            app.add_message::<BoxingError>();
+            app.add_message::<BoxingToggled>();
+            app.add_message::<CameraBoxed>();
+            app.world_mut().spawn((
+                Window {
+                    resolution: window_resolution,
+                    ..Window::default()
+                },
+                PrimaryWindow,
+            ));
+            let camera_id = app
+                .world_mut()
+                .spawn((
+                    Camera {
+                        viewport: None,
+                        is_active: true,
+                        ..Camera::default()
+                    },
+                    RenderTarget::Window(WindowRef::Primary),
+                    camerabox,
+                ))
+                .id();
+            app.add_systems(First, adjust_viewport);
+            (app, camera_id)
+        }
+
+        #[test]
+        fn test_camera_box_requires_computed_boxing() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W720P.into(),
+            );
+            assert!(app.world().get::<ComputedBoxing>(camera_id).is_some());
+            app.update();
+            assert_eq!(
+                app.world()
+                    .get::<ComputedBoxing>(camera_id)
+                    .unwrap()
+                    .output_resolution,
+                W360P.as_vec2()
+            );
+
+            let existing_camera = app
+                .world_mut()
+                .spawn((Camera::default(), RenderTarget::Window(WindowRef::Primary)))
+                .id();
+            assert!(app.world().get::<ComputedBoxing>(existing_camera).is_none());
+            app.world_mut()
+                .entity_mut(existing_camera)
+                .insert(CameraBox::StaticResolution {
+                    resolution: W180P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                });
+            assert!(app.world().get::<ComputedBoxing>(existing_camera).is_some());
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(existing_camera)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_size, W180P);
+        }
+
+        #[test]
+        fn test_basic_windowboxing() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::WindowBox {
+                    left: 10,
+                    right: 10,
+                    top: 10,
+                    bottom: 10,
+                    strict_windowboxing: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(10, 10));
+            assert_eq!(viewport.physical_size, UVec2::new(620, 340));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::WindowBox {
+                    left: 10,
+                    right: 10,
+                    top: 10,
+                    bottom: 10,
+                    strict_windowboxing: true,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(10, 10));
+            assert_eq!(viewport.physical_size, UVec2::new(620, 340));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::WindowBox {
+                    left: 650,
+                    right: 0,
+                    top: 370,
+                    bottom: 0,
+                    strict_windowboxing: true,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            assert!(viewport.is_none());
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::WindowBox {
+                    left: 650,
+                    right: 0,
+                    top: 370,
+                    bottom: 0,
+                    strict_windowboxing: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(322, 182));
+            assert_eq!(viewport.physical_size, UVec2::new(320, 180));
+        }
+
+        #[test]
+        fn test_basic_pillarboxing() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::PillarBox {
+                    left: 2,
+                    right: 2,
+                    strict_pillarboxing: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(2, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(636, 360));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::PillarBox {
+                    left: 5,
+                    right: 0,
+                    strict_pillarboxing: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(5, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(635, 360));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::PillarBox {
+                    left: 0,
+                    right: 5,
+                    strict_pillarboxing: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(635, 360));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::PillarBox {
+                    left: 5,
+                    right: 10,
+                    strict_pillarboxing: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(5, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(625, 360));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::PillarBox {
+                    left: 10,
+                    right: 5,
+                    strict_pillarboxing: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(10, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(625, 360));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::PillarBox {
+                    left: 640,
+                    right: 0,
+                    strict_pillarboxing: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(320, 0));
+            assert_eq!(viewport.physical_size, UVec2::from(W180P).with_y(360));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::PillarBox {
+                    left: 2,
+                    right: 2,
+                    strict_pillarboxing: true,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(2, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(636, 360));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::PillarBox {
+                    left: 5,
+                    right: 0,
+                    strict_pillarboxing: true,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(5, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(635, 360));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::PillarBox {
+                    left: 0,
+                    right: 5,
+                    strict_pillarboxing: true,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(635, 360));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::PillarBox {
+                    left: 5,
+                    right: 10,
+                    strict_pillarboxing: true,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(5, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(625, 360));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::PillarBox {
+                    left: 10,
+                    right: 5,
+                    strict_pillarboxing: true,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(10, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(625, 360));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::PillarBox {
+                    left: 640,
+                    right: 0,
+                    strict_pillarboxing: true,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            assert!(viewport.is_none());
+        }
+
+        #[test]
+        fn test_basic_letterboxing() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 2,
+                    bottom: 2,
+                    strict_letterboxing: true,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 2));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 356));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 5,
+                    bottom: 0,
+                    strict_letterboxing: true,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 5));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 355));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 0,
+                    bottom: 5,
+                    strict_letterboxing: true,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 355));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 10,
+                    bottom: 5,
+                    strict_letterboxing: true,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 10));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 345));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 5,
+                    bottom: 10,
+                    strict_letterboxing: true,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 5));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 345));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 360,
+                    bottom: 0,
+                    strict_letterboxing: true,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            assert!(viewport.is_none());
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 2,
+                    bottom: 2,
+                    strict_letterboxing: false,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 2));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 356));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 5,
+                    bottom: 0,
+                    strict_letterboxing: false,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 5));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 355));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 0,
+                    bottom: 5,
+                    strict_letterboxing: false,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 355));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 10,
+                    bottom: 5,
+                    strict_letterboxing: false,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 10));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 345));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 5,
+                    bottom: 10,
+                    strict_letterboxing: false,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 5));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 345));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 360,
+                    bottom: 0,
+                    strict_letterboxing: false,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 180));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 180));
+        }
+
+        #[test]
+        fn test_letterbox_preserve_bar_ratio() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 200,
+                    bottom: 100,
+                    strict_letterboxing: false,
+                    preserve_bar_ratio: true,
+                    logical_bars: false,
+                },
+                UVec2::new(640, 240).into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            let top_bar = viewport.physical_position.y;
+            let bottom_bar = 240 - viewport.physical_position.y - viewport.physical_size.y;
+            assert_eq!(viewport.physical_size, UVec2::new(640, 120));
+            assert_eq!(top_bar, 80);
+            assert_eq!(bottom_bar, 40);
+            assert_eq!(top_bar, bottom_bar * 2);
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 200,
+                    bottom: 100,
+                    strict_letterboxing: false,
+                    preserve_bar_ratio: true,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 200));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 60));
+        }
+
+        #[test]
+        fn test_logical_bars() {
+            let mut resolution = WindowResolution::from(W720P);
+            resolution.set_scale_factor(1.5);
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 10,
+                    bottom: 10,
+                    strict_letterboxing: false,
+                    preserve_bar_ratio: false,
+                    logical_bars: true,
+                },
+                resolution,
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            let top_bar = viewport.physical_position.y;
+            let bottom_bar = W720P.y - viewport.physical_position.y - viewport.physical_size.y;
+            assert_eq!(top_bar, 15);
+            assert_eq!(bottom_bar, 15);
+
+            // 11 logical pixels is 16.5 physical pixels, so the bars can't be split evenly.
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(CameraBox::PillarBox {
+                    left: 11,
+                    right: 11,
+                    strict_pillarboxing: false,
+                    logical_bars: true,
+                });
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            let left_bar = viewport.physical_position.x;
+            let right_bar = W720P.x - viewport.physical_position.x - viewport.physical_size.x;
+            assert_eq!(left_bar, 16);
+            assert_eq!(right_bar, 17);
+            assert_eq!(left_bar + right_bar, (22. * 1.5_f32).round() as u32);
+        }
+
+        #[test]
+        fn test_scale_factor_override() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 10,
+                    bottom: 10,
+                    strict_letterboxing: false,
+                    preserve_bar_ratio: false,
+                    logical_bars: true,
+                },
+                W720P.into(),
+            );
+            let viewport = |app: &App| {
+                let viewport = app
+                    .world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .to_owned()
+                    .viewport
+                    .unwrap();
+                (viewport.physical_position, viewport.physical_size)
+            };
+            app.update();
+            assert_eq!(viewport(&app), (UVec2::new(0, 10), UVec2::new(1280, 700)));
+
+            // The window's scale factor is 1, but the bars are sized as if it were 2.
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(ScaleFactorOverride(2.));
+            app.update();
+            assert_eq!(viewport(&app), (UVec2::new(0, 20), UVec2::new(1280, 680)));
+        }
+
+        #[test]
+        fn test_resolution_scale_exact() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P.into(),
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                UVec2::new(1920, 1080).into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            let viewport = viewport.unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(640, 360));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 360));
+        }
+
+        #[test]
+        fn test_resolution_scale_integer_fit() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P.into(),
+                    position: None,
+                    scale: ResolutionScaleMode::IntegerFit,
+                },
+                UVec2::new(1920, 1080).into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            assert!(viewport.is_none());
+        }
+
+        #[test]
+        fn test_resolution_scale_stretch_fit() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P.into(),
+                    position: None,
+                    scale: ResolutionScaleMode::StretchFit,
+                },
+                UVec2::new(1920, 1080).into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            assert!(viewport.is_none());
+        }
+
+        #[test]
+        fn test_resolution_scale_integer_fit_boxed() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P.into(),
+                    position: None,
+                    scale: ResolutionScaleMode::IntegerFit,
+                },
+                UVec2::new(2000, 1100).into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            let viewport = viewport.unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(40, 10));
+            assert_eq!(viewport.physical_size, UVec2::new(1920, 1080));
+        }
+
+        #[test]
+        fn test_resolution_scale_stretch_fit_boxed() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P.into(),
+                    position: None,
+                    scale: ResolutionScaleMode::StretchFit,
+                },
+                UVec2::new(2000, 1100).into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            let viewport = viewport.unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(22, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(1956, 1100));
+        }
+
+        #[test]
+        fn test_basic_resolution() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P.into(),
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            assert!(viewport.is_none());
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P.into(),
+                    position: Some((1, 0).into()),
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            assert!(viewport.is_none());
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P.into(),
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W720P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(320, 180));
+            assert_eq!(viewport.physical_size, W360P);
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P.into(),
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W180P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 0));
+            assert_eq!(viewport.physical_size, W180P);
+        }
+
+        #[test]
+        fn test_resolution_position_near_far_edge() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: UVec2::new(100, 100),
+                    position: Some((500, 250).into()),
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(500, 250));
+            assert_eq!(viewport.physical_size, UVec2::new(100, 100));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: UVec2::new(100, 100),
+                    position: Some((540, 260).into()),
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W360P.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(540, 260));
+            assert_eq!(viewport.physical_size, UVec2::new(100, 100));
+        }
+
+        #[test]
+        fn test_pending_target_size() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W720P.into(),
+            );
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(PendingTargetSize(Some(UVec2::new(1920, 1080))));
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(640, 360));
+            assert_eq!(viewport.physical_size, W360P);
+            assert!(
+                app.world()
+                    .get::<PendingTargetSize>(camera_id)
+                    .unwrap()
+                    .0
+                    .is_none()
+            );
+
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(320, 180));
+            assert_eq!(viewport.physical_size, W360P);
+        }
+
+        #[test]
+        fn test_temporary_box() {
+            let original = CameraBox::StaticResolution {
+                resolution: W360P,
+                position: None,
+                scale: ResolutionScaleMode::Exact,
+            };
+            let (mut app, camera_id) = setup_app(original.clone(), W720P.into());
+            app.init_resource::<Time>();
+            app.add_systems(First, update_temporary_boxes);
+            app.update();
+
+            let temporary = CameraBox::LetterBox {
+                top: 10,
+                bottom: 10,
+                strict_letterboxing: false,
+                preserve_bar_ratio: false,
+                logical_bars: false,
+            };
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(TemporaryBox::new(temporary.clone(), Duration::from_secs(3)));
+            app.update();
+            assert_eq!(app.world().get::<CameraBox>(camera_id), Some(&temporary));
+            assert_eq!(
+                app.world().get::<TemporaryBox>(camera_id).unwrap().previous,
+                Some(original.clone())
+            );
+
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(2));
+            app.update();
+            assert_eq!(app.world().get::<CameraBox>(camera_id), Some(&temporary));
+
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(2));
+            app.update();
+            assert_eq!(app.world().get::<CameraBox>(camera_id), Some(&original));
+            assert!(app.world().get::<TemporaryBox>(camera_id).is_none());
+        }
+
+        #[test]
+        fn test_aspect_ratio_tween() {
+            let target = UVec2::new(2560, 1080);
+            let (mut app, camera_id) = setup_app(CameraBox::aspect_16_9(), target.into());
+            app.init_resource::<Time>();
+            app.add_systems(First, update_aspect_ratio_tweens.before(adjust_viewport));
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(AspectRatioTween::new(
+                    AspectRatio::SIXTEEN_NINE,
+                    AspectRatio::ULTRAWIDE,
+                    Duration::from_secs(4),
+                ));
+            let width = |app: &App| {
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .as_ref()
+                    .map_or(target.x, |viewport| viewport.physical_size.x)
+            };
+
+            app.update();
+            let mut previous_width = width(&app);
+            assert_eq!(previous_width, 1920);
+            for second in 1..=4 {
+                app.world_mut()
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_secs(1));
+                app.update();
+                let width = width(&app);
+                assert!(width > previous_width, "{width} <= {previous_width}");
+                previous_width = width;
+
+                // Halfway through, the ratio is the geometric mean of the two.
+                if second == 2 {
+                    assert_eq!(width, 2200);
+                }
+            }
+            assert_eq!(previous_width, 2520);
+            assert!(app.world().get::<AspectRatioTween>(camera_id).is_none());
+            assert_eq!(
+                app.world().get::<CameraBox>(camera_id),
+                Some(&CameraBox::static_aspect_ratio(AspectRatio::ULTRAWIDE))
+            );
+        }
+
+        #[test]
+        fn test_normalized_content_rect() -> Result<()> {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W720P.into(),
+            );
+            app.update();
+
+            let content_rect = |app: &mut App| {
+                let primary_window = app
+                    .world_mut()
+                    .query_filtered::<Entity, With<PrimaryWindow>>()
+                    .single(app.world())
+                    .ok();
+                let mut windows = app.world_mut().query::<(Entity, &Window)>();
+                let world = app.world();
+                normalized_content_rect(
+                    world.get::<Camera>(camera_id).unwrap(),
+                    world.get::<RenderTarget>(camera_id).unwrap(),
+                    primary_window,
+                    windows.iter(world),
+                    world.resource::<Assets<Image>>(),
+                    world.resource::<ManualTextureViews>(),
+                )
+            };
+
+            assert_eq!(
+                content_rect(&mut app),
+                Some(Rect::new(0.25, 0.25, 0.75, 0.75))
+            );
+
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::try_new(1., 1.)?,
+                    position: None,
+                    strict: false,
+                });
+            let mut image = Image::default();
+            image.texture_descriptor.size = Extent3d {
+                width: 400,
+                height: 200,
+                depth_or_array_layers: 1,
+            };
+            let handle = app.world_mut().resource_mut::<Assets<Image>>().add(image);
+            *app.world_mut().get_mut::<RenderTarget>(camera_id).unwrap() =
+                RenderTarget::Image(handle.into());
+            app.update();
+            assert_eq!(content_rect(&mut app), Some(Rect::new(0.25, 0., 0.75, 1.)));
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_zero_sized_image_target() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W180P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W720P.into(),
+            );
+            let custom_viewport = Viewport {
+                physical_position: UVec2::new(10, 10),
+                physical_size: W180P,
+                ..Viewport::default()
+            };
+            app.world_mut()
+                .get_mut::<Camera>(camera_id)
+                .unwrap()
+                .viewport = Some(custom_viewport);
+
+            let mut image = Image::default();
+            image.texture_descriptor.size = Extent3d {
+                width: 0,
+                height: 0,
+                depth_or_array_layers: 1,
+            };
+            let handle = app.world_mut().resource_mut::<Assets<Image>>().add(image);
+            *app.world_mut().get_mut::<RenderTarget>(camera_id).unwrap() =
+                RenderTarget::Image(handle.clone().into());
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(10, 10));
+            assert_eq!(viewport.physical_size, W180P);
+
+            app.world_mut()
+                .resource_mut::<Assets<Image>>()
+                .get_mut(&handle)
+                .unwrap()
+                .texture_descriptor
+                .size = Extent3d {
+                width: W360P.x,
+                height: W360P.y,
+                depth_or_array_layers: 1,
+            };
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(160, 90));
+            assert_eq!(viewport.physical_size, W180P);
+        }
+
+        #[test]
+        fn test_aspect_ratio_image_target() -> Result<()> {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::try_new(1., 1.)?,
+                    position: None,
+                    strict: false,
+                },
+                W720P.into(),
+            );
+
+            let mut image = Image::default();
+            image.texture_descriptor.size = Extent3d {
+                width: 400,
+                height: 200,
+                depth_or_array_layers: 1,
+            };
+            let handle = app.world_mut().resource_mut::<Assets<Image>>().add(image);
+            *app.world_mut().get_mut::<RenderTarget>(camera_id).unwrap() =
+                RenderTarget::Image(handle.into());
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(100, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(200, 200));
+
+            let mut image = Image::default();
+            image.texture_descriptor.size = Extent3d {
+                width: 200,
+                height: 400,
+                depth_or_array_layers: 1,
+            };
+            let handle = app.world_mut().resource_mut::<Assets<Image>>().add(image);
+            *app.world_mut().get_mut::<RenderTarget>(camera_id).unwrap() =
+                RenderTarget::Image(handle.into());
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 100));
+            assert_eq!(viewport.physical_size, UVec2::new(200, 200));
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_aspect_ratio_anchor() -> Result<()> {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::try_from(W720P.as_vec2())?,
+                    position: None,
+                    strict: false,
+                },
+                UVec2::new(720, 1280).into(),
+            );
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(BoxAnchor::Bottom);
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 875));
+            assert_eq!(viewport.physical_size, UVec2::new(720, 405));
+            assert_eq!(
+                viewport.physical_position.y + viewport.physical_size.y,
+                1280
+            );
+
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(BoxAnchor::TopLeft);
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::ZERO);
+            assert_eq!(viewport.physical_size, UVec2::new(720, 405));
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_min_content_fraction() {
+            let camera_box = |min_content_fraction| CameraBox::ResolutionIntegerScale {
+                resolution: W360P,
+                allow_imperfect_downscaled_boxing: false,
+                independent_axis_scale: false,
+                fit: IntegerScaleFit::FitInside,
+                force_downscale: false,
+                min_content_fraction,
+            };
+
+            // At 2x the resolution only covers 40% of the window.
+            let (mut app, camera_id) = setup_app(camera_box(None), UVec2::new(1280, 1800).into());
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 540));
+            assert_eq!(viewport.physical_size, W720P);
+
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(camera_box(Some(0.35)));
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_size, W720P);
+
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(camera_box(Some(0.5)));
+            app.update();
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_none()
+            );
+        }
+
+        #[test]
+        fn test_all_anchors() -> Result<()> {
+            let modes = [
+                CameraBox::static_aspect_ratio(AspectRatio::try_new(4., 3.)?),
+                CameraBox::ResolutionIntegerScale {
+                    resolution: UVec2::new(320, 240),
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
+                },
+            ];
+            let anchors = [
+                (BoxAnchor::TopLeft, UVec2::new(0, 0)),
+                (BoxAnchor::Top, UVec2::new(160, 0)),
+                (BoxAnchor::TopRight, UVec2::new(320, 0)),
+                (BoxAnchor::Left, UVec2::new(0, 0)),
+                (BoxAnchor::Center, UVec2::new(160, 0)),
+                (BoxAnchor::Right, UVec2::new(320, 0)),
+                (BoxAnchor::BottomLeft, UVec2::new(0, 0)),
+                (BoxAnchor::Bottom, UVec2::new(160, 0)),
+                (BoxAnchor::BottomRight, UVec2::new(320, 0)),
+            ];
+
+            for mode in modes {
+                let (mut app, camera_id) = setup_app(mode, W720P.into());
+                for (anchor, position) in anchors {
+                    app.world_mut().entity_mut(camera_id).insert(anchor);
+                    app.update();
+                    let viewport = app
+                        .world()
+                        .get::<Camera>(camera_id)
+                        .unwrap()
+                        .to_owned()
+                        .viewport
+                        .unwrap();
+                    assert_eq!(viewport.physical_position, position, "{anchor:?}");
+                    assert_eq!(viewport.physical_size, UVec2::new(960, 720), "{anchor:?}");
+                }
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn test_image_target_removed() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W180P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W720P.into(),
+            );
+
+            let mut image = Image::default();
+            image.texture_descriptor.size = Extent3d {
+                width: W360P.x,
+                height: W360P.y,
+                depth_or_array_layers: 1,
+            };
+            let handle = app.world_mut().resource_mut::<Assets<Image>>().add(image);
+            *app.world_mut().get_mut::<RenderTarget>(camera_id).unwrap() =
+                RenderTarget::Image(handle.clone().into());
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            assert!(viewport.is_some());
+
+            app.world_mut()
+                .resource_mut::<Assets<Image>>()
+                .remove(&handle);
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            assert!(viewport.is_none());
+            let errors = app.world().resource::<Messages<BoxingError>>();
+            let mut errors_reader = errors.get_cursor();
+            let error = errors_reader.read(errors).next();
+            assert!(matches!(
+                error,
+                Some(BoxingError::TargetMissing { camera }) if *camera == camera_id
+            ));
+        }
+
+        #[test]
+        fn test_8k_boxing_is_symmetric() -> Result<()> {
+            const W8K: UVec2 = UVec2::new(7680, 4320);
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::try_new(4., 3.)?,
+                    position: None,
+                    strict: false,
+                },
+                W8K.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(960, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(5760, 4320));
+            assert_eq!(
+                viewport.physical_position.x,
+                W8K.x - viewport.physical_position.x - viewport.physical_size.x
+            );
+
+            // 2.35:1 doesn't divide 8K evenly (it would be 3268.09 pixels tall), so the output has
+            // to be rounded before the bars are calculated from it to keep them the same size.
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::try_new(2.35, 1.)?,
+                    position: None,
+                    strict: false,
+                },
+                W8K.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 526));
+            assert_eq!(viewport.physical_size, UVec2::new(7680, 3268));
+            assert_eq!(
+                viewport.physical_position.y,
+                W8K.y - viewport.physical_position.y - viewport.physical_size.y
+            );
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::ResolutionIntegerScale {
+                    resolution: UVec2::new(1280, 960),
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
+                },
+                W8K.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(1280, 240));
+            assert_eq!(viewport.physical_size, UVec2::new(5120, 3840));
+            assert_eq!(
+                viewport.physical_position,
+                W8K - viewport.physical_position - viewport.physical_size
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_fixed_content_size() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::FixedContentHeight { height: 720 },
+                UVec2::new(1920, 1080).into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 180));
+            assert_eq!(viewport.physical_size, UVec2::new(1920, 720));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::FixedContentHeight { height: 720 },
+                UVec2::new(800, 600).into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            assert!(viewport.is_none());
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::FixedContentWidth { width: 1280 },
+                UVec2::new(1920, 1080).into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(320, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(1280, 1080));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::FixedContentWidth { width: 1280 },
+                UVec2::new(800, 600).into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            assert!(viewport.is_none());
+        }
+
+        #[test]
+        fn test_constant_vertical_extent() {
+            let world_height = 10.;
+            let setup = |window_size: UVec2| {
+                let (mut app, camera_id) = setup_app(
+                    CameraBox::ConstantVerticalExtent { world_height },
+                    window_size.into(),
+                );
+                app.add_systems(First, sync_vertical_extents.before(adjust_viewport));
+                app.world_mut()
+                    .entity_mut(camera_id)
+                    .insert(Projection::Orthographic(
+                        OrthographicProjection::default_2d(),
+                    ));
+                app.update();
+                (app, camera_id)
+            };
+            let visible_height = |app: &App, camera_id: Entity| match app
+                .world()
+                .get::<Projection>(camera_id)
+                .unwrap()
+            {
+                Projection::Orthographic(OrthographicProjection {
+                    scaling_mode: ScalingMode::FixedVertical { viewport_height },
+                    ..
+                }) => Some(*viewport_height),
+                _ => None,
+            };
+
+            // A wide window shows more of the world to the sides, without being pillarboxed.
+            let (app, camera_id) = setup(W720P);
+            assert_eq!(visible_height(&app, camera_id), Some(world_height));
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_none()
+            );
+
+            // A narrow window is letterboxed to a square, rather than showing less world than tall.
+            let (app, camera_id) = setup(UVec2::new(360, 640));
+            assert_eq!(visible_height(&app, camera_id), Some(world_height));
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 140));
+            assert_eq!(viewport.physical_size, UVec2::new(360, 360));
+        }
+
+        #[test]
+        fn test_integer_scale_area_match() {
+            // 1.8x of 360p
+            let window = UVec2::new(1152, 648);
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::ResolutionIntegerScale {
+                    resolution: W360P,
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
+                },
+                window.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(256, 144));
+            assert_eq!(viewport.physical_size, W360P);
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::ResolutionIntegerScale {
+                    resolution: W360P,
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::AreaMatch,
+                    force_downscale: false,
+                    min_content_fraction: None,
+                },
+                window.into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            assert!(viewport.is_none());
+
+            // 1.3x of 360p, the fitting scale is still the closest.
+            let (mut app, camera_id) = setup_app(
+                CameraBox::ResolutionIntegerScale {
+                    resolution: W360P,
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::AreaMatch,
+                    force_downscale: false,
+                    min_content_fraction: None,
+                },
+                UVec2::new(832, 468).into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(96, 54));
+            assert_eq!(viewport.physical_size, W360P);
+        }
+
+        #[test]
+        fn test_nearest_integer_scale() {
+            let boxed_viewport = |fit, window: UVec2| {
+                let (mut app, camera_id) = setup_app(
+                    CameraBox::ResolutionIntegerScale {
+                        resolution: W180P,
+                        allow_imperfect_downscaled_boxing: false,
+                        independent_axis_scale: false,
+                        fit,
+                        force_downscale: false,
+                        min_content_fraction: None,
+                    },
+                    window.into(),
+                );
+                app.update();
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .to_owned()
+                    .viewport
+                    .map(|viewport| (viewport.physical_position, viewport.physical_size))
+            };
 
-        // Recheck with the current values
-        let has_int_scale = desired_aspect_ratio.ratio() == physical_aspect_ratio.ratio()
-            && (height_scale % 1. == 0. && width_scale % 1. == 0.);
+            // 3.125x of 180p, so both use 3x.
+            let window = UVec2::new(1000, 600);
+            assert_eq!(
+                boxed_viewport(IntegerScaleFit::FitInside, window),
+                Some((UVec2::new(20, 30), UVec2::new(960, 540)))
+            );
+            assert_eq!(
+                boxed_viewport(IntegerScaleFit::Nearest, window),
+                Some((UVec2::new(20, 30), UVec2::new(960, 540)))
+            );
 
-        // Integer Scaling Exists
-        if has_int_scale {
-            return Ok(None);
-        }
+            // 3.89x of 180p, so the nearest scale is 4x, which is cropped vertically.
+            let window = UVec2::new(1300, 700);
+            assert_eq!(
+                boxed_viewport(IntegerScaleFit::FitInside, window),
+                Some((UVec2::new(170, 80), UVec2::new(960, 540)))
+            );
+            assert_eq!(
+                boxed_viewport(IntegerScaleFit::Nearest, window),
+                Some((UVec2::new(10, 0), UVec2::new(1280, 700)))
+            );
 
-        let best_divisor = if height_scale < width_scale {
-            width_scale
-        } else {
-            height_scale
+            // 4x fills the window on both axes, so nothing is boxed.
+            assert_eq!(
+                boxed_viewport(IntegerScaleFit::Nearest, UVec2::new(1270, 700)),
+                None
+            );
         }
-        .ceil();
 
-        let render_height = desired_size.y / best_divisor;
-        let render_width = desired_size.x / best_divisor;
+        #[test]
+        fn test_boxing_toggled() -> Result<()> {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W360P.into(),
+            );
+            let window = app
+                .world_mut()
+                .query_filtered::<Entity, With<PrimaryWindow>>()
+                .single(app.world())?;
+            let mut toggled_reader = app
+                .world()
+                .resource::<Messages<BoxingToggled>>()
+                .get_cursor();
+            let mut read_toggles = |app: &App| {
+                let messages = app.world().resource::<Messages<BoxingToggled>>();
+                toggled_reader
+                    .read(messages)
+                    .map(|toggle| toggle.now_boxing)
+                    .collect::<Vec<_>>()
+            };
 
-        let letterbox_size = physical_size.y - render_height;
-        let pillarbox_size = physical_size.x - render_width;
-        Ok(Some(Boxing {
-            boxing_offset: Vec2::new(pillarbox_size / 2., letterbox_size / 2.),
-            output_resolution: Vec2::new(render_width, render_height),
-        }))
-    } else {
-        let best_scale = if width_scale > height_scale {
-            height_scale
-        } else {
-            width_scale
-        }
-        .floor();
+            app.update();
+            assert!(read_toggles(&app).is_empty());
 
-        let render_width = desired_size.x * best_scale;
-        let render_height = desired_size.y * best_scale;
+            app.world_mut()
+                .get_mut::<Window>(window)
+                .unwrap()
+                .resolution
+                .set_physical_resolution(W720P.x, W720P.y);
+            app.update();
+            assert_eq!(read_toggles(&app), vec![true]);
 
-        let letterbox_size = physical_size.y - render_height;
-        let pillarbox_size = physical_size.x - render_width;
-        Ok(Some(Boxing {
-            boxing_offset: Vec2::new(pillarbox_size / 2., letterbox_size / 2.),
-            output_resolution: Vec2::new(render_width, render_height),
-        }))
-    }
-}
+            app.world_mut()
+                .get_mut::<Window>(window)
+                .unwrap()
+                .resolution
+                .set_physical_resolution(1920, 1080);
+            app.update();
+            assert!(read_toggles(&app).is_empty());
 
-fn calculate_letterbox(physical_size: &Vec2, letterbox: (&u32, &u32)) -> Boxing {
-    let letterbox_height = (letterbox.0 + letterbox.1) as f32;
-    let render_width = physical_size.x;
-    let render_height = physical_size.y - letterbox_height;
+            app.world_mut()
+                .get_mut::<Window>(window)
+                .unwrap()
+                .resolution
+                .set_physical_resolution(W360P.x, W360P.y);
+            app.update();
+            assert_eq!(read_toggles(&app), vec![false]);
 
-    Boxing {
-        boxing_offset: Vec2::new(0., *letterbox.0 as f32),
-        output_resolution: Vec2::new(render_width, render_height),
-    }
-}
+            app.update();
+            assert!(read_toggles(&app).is_empty());
 
-fn calculate_pillarbox(physical_size: &Vec2, pillarbox: (&u32, &u32)) -> Boxing {
-    let pillarbox_width = (pillarbox.0 + pillarbox.1) as f32;
-    let render_height = physical_size.y;
-    let render_width = physical_size.x - pillarbox_width;
+            // Cameras that stop being boxed without the boxing being calculated are unboxed too.
+            app.world_mut()
+                .get_mut::<Window>(window)
+                .unwrap()
+                .resolution
+                .set_physical_resolution(W720P.x, W720P.y);
+            app.update();
+            assert_eq!(read_toggles(&app), vec![true]);
 
-    Boxing {
-        boxing_offset: Vec2::new(*pillarbox.0 as f32, 0.),
-        output_resolution: Vec2::new(render_width, render_height),
-    }
-}
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(CameraBoxEnabled(false));
+            app.update();
+            assert_eq!(read_toggles(&app), vec![false]);
+            app.update();
+            assert!(read_toggles(&app).is_empty());
 
-fn calculate_windowbox(physical_size: &Vec2, windowbox: [(&u32, &u32); 2]) -> Boxing {
-    let letterbox_height = (windowbox[0].0 + windowbox[0].1) as f32;
-    let pillarbox_width = (windowbox[1].0 + windowbox[1].1) as f32;
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(CameraBoxEnabled(true));
+            app.update();
+            assert_eq!(read_toggles(&app), vec![true]);
 
-    let render_height = physical_size.y - letterbox_height;
-    let render_width = physical_size.x - pillarbox_width;
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(InactiveBox(None));
+            app.world_mut()
+                .get_mut::<Camera>(camera_id)
+                .unwrap()
+                .is_active = false;
+            app.update();
+            assert_eq!(read_toggles(&app), vec![false]);
 
-    Boxing {
-        boxing_offset: Vec2::new(*windowbox[1].0 as f32, *windowbox[0].0 as f32),
-        output_resolution: Vec2::new(render_width, render_height),
-    }
-}
+            app.world_mut()
+                .get_mut::<Camera>(camera_id)
+                .unwrap()
+                .is_active = true;
+            app.update();
+            assert_eq!(read_toggles(&app), vec![true]);
 
-fn is_within_rect(rect: &UVec2, position: &UVec2, size: &UVec2) -> bool {
-    let actual_bounds = position + size;
-    rect.x >= actual_bounds.x && rect.y >= actual_bounds.y
-}
+            app.world_mut()
+                .resource_mut::<CameraBoxingSettings>()
+                .unbox_in_exclusive_fullscreen = true;
+            app.world_mut().get_mut::<Window>(window).unwrap().mode =
+                WindowMode::Fullscreen(MonitorSelection::Primary, VideoModeSelection::Current);
+            app.update();
+            assert_eq!(read_toggles(&app), vec![false]);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    impl Boxing {
-        fn new(boxing_offset: Vec2, output_resolution: Vec2) -> Self {
-            Boxing {
-                boxing_offset,
-                output_resolution,
-            }
+            Ok(())
         }
-    }
-
-    mod internal {
-        use super::*;
 
         #[test]
-        fn test_aspect_ratio_scaling() -> Result<()> {
-            assert_eq!(
-                calculate_boxing_from_aspect_ratios(
-                    &Vec2::new(640., 360.),
-                    &AspectRatio::try_new(640., 360.)?,
-                    &AspectRatio::try_new(640., 360.)?
-                ),
-                Boxing::new(Vec2::ZERO, Vec2::new(640., 360.))
+        fn test_camera_boxed() -> Result<()> {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W360P.into(),
             );
+            let window = app
+                .world_mut()
+                .query_filtered::<Entity, With<PrimaryWindow>>()
+                .single(app.world())?;
+            let mut boxed_reader = app.world().resource::<Messages<CameraBoxed>>().get_cursor();
+            let mut read_boxed = |app: &App| {
+                let messages = app.world().resource::<Messages<CameraBoxed>>();
+                boxed_reader.read(messages).cloned().collect::<Vec<_>>()
+            };
 
-            assert_eq!(
-                calculate_boxing_from_aspect_ratios(
-                    &Vec2::new(1280., 720.),
-                    &AspectRatio::try_new(1280., 720.)?,
-                    &AspectRatio::try_new(640., 360.)?
-                ),
-                Boxing::new(Vec2::ZERO, Vec2::new(1280., 720.))
-            );
+            app.update();
+            assert!(read_boxed(&app).is_empty());
 
-            assert_eq!(
-                calculate_boxing_from_aspect_ratios(
-                    &Vec2::new(1920., 1080.),
-                    &AspectRatio::try_new(1920., 1080.)?,
-                    &AspectRatio::try_new(1280., 720.)?
-                ),
-                Boxing::new(Vec2::ZERO, Vec2::new(1920., 1080.))
-            );
+            app.world_mut()
+                .get_mut::<Window>(window)
+                .unwrap()
+                .resolution
+                .set_physical_resolution(W720P.x, W720P.y);
+            app.update();
+            let boxed = read_boxed(&app);
+            assert_eq!(boxed.len(), 1);
+            assert_eq!(boxed[0].camera, camera_id);
+            assert_eq!(boxed[0].boxing_offset, Vec2::new(320., 180.));
+            assert_eq!(boxed[0].output_resolution, W360P.as_vec2());
+            let viewport = boxed[0].viewport.as_ref().unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(320, 180));
+            assert_eq!(viewport.physical_size, W360P);
 
-            assert_eq!(
-                calculate_boxing_from_aspect_ratios(
-                    &Vec2::new(640., 480.),
-                    &AspectRatio::try_new(640., 480.)?,
-                    &AspectRatio::try_new(640., 360.)?
-                ),
-                Boxing::new(Vec2::new(0., 60.), Vec2::new(640., 360.))
-            );
+            app.update();
+            assert!(read_boxed(&app).is_empty());
 
-            assert_eq!(
-                calculate_boxing_from_aspect_ratios(
-                    &Vec2::new(640., 360.),
-                    &AspectRatio::try_new(640., 360.)?,
-                    &AspectRatio::try_new(640., 480.)?
-                ),
-                Boxing::new(Vec2::new(80., 0.), Vec2::new(480., 360.))
-            );
+            // Disabling the boxing clears the viewport, which is a change too.
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(CameraBoxEnabled(false));
+            app.update();
+            let boxed = read_boxed(&app);
+            assert_eq!(boxed.len(), 1);
+            assert!(boxed[0].viewport.is_none());
+            app.update();
+            assert!(read_boxed(&app).is_empty());
 
-            assert_eq!(
-                calculate_boxing_from_aspect_ratios(
-                    &Vec2::new(480., 640.),
-                    &AspectRatio::try_new(480., 640.)?,
-                    &AspectRatio::try_new(1280., 720.)?
-                ),
-                Boxing::new(Vec2::new(0., 185.), Vec2::new(480., 270.))
-            );
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(CameraBoxEnabled(true));
+            app.update();
+            let boxed = read_boxed(&app);
+            assert_eq!(boxed.len(), 1);
+            assert!(boxed[0].viewport.is_some());
+
+            app.world_mut()
+                .resource_mut::<CameraBoxingSettings>()
+                .unbox_in_exclusive_fullscreen = true;
+            app.world_mut().get_mut::<Window>(window).unwrap().mode =
+                WindowMode::Fullscreen(MonitorSelection::Primary, VideoModeSelection::Current);
+            app.update();
+            let boxed = read_boxed(&app);
+            assert_eq!(boxed.len(), 1);
+            assert!(boxed[0].viewport.is_none());
 
-            assert_eq!(
-                calculate_boxing_from_aspect_ratios(
-                    &Vec2::new(1280., 720.),
-                    &AspectRatio::try_new(1280., 720.)?,
-                    &AspectRatio::try_new(480., 640.)?
-                ),
-                Boxing::new(Vec2::new(370., 0.), Vec2::new(540., 720.))
-            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_boxing_changed_this_frame() -> Result<()> {
+            let (mut app, _) = setup_app(CameraBox::static_resolution(W360P), W360P.into());
+            app.init_resource::<BoxingChangedThisFrame>();
+            app.add_systems(First, reset_boxing_changed.before(adjust_viewport));
+            let window = app
+                .world_mut()
+                .query_filtered::<Entity, With<PrimaryWindow>>()
+                .single(app.world())?;
+            let changed = |app: &App| app.world().resource::<BoxingChangedThisFrame>().0;
+
+            app.update();
+            assert!(!changed(&app));
+
+            app.world_mut()
+                .get_mut::<Window>(window)
+                .unwrap()
+                .resolution
+                .set_physical_resolution(W720P.x, W720P.y);
+            app.update();
+            assert!(changed(&app));
+
+            app.update();
+            assert!(!changed(&app));
 
             Ok(())
         }
 
         #[test]
-        fn test_calculate_boxing_imperfect() {
-            assert!(
-                calculate_boxing_imperfect(&Vec2::new(640., 360.), &Vec2::new(640., 360.))
-                    .is_ok_and(|u| u.is_none()),
-                "Testing against the same resolution failed! (360p -> 360p)",
+        fn test_min_content_size() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 300,
+                    bottom: 300,
+                    strict_letterboxing: true,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                },
+                W720P.into(),
             );
+            app.world_mut()
+                .resource_mut::<CameraBoxingSettings>()
+                .min_content_size = UVec2::new(0, 240);
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 240));
+            assert_eq!(viewport.physical_size, UVec2::new(1280, 240));
 
-            // Test Output with Expected Boxing
-            assert!(
-                calculate_boxing_imperfect(&Vec2::new(1920., 1080.), &Vec2::new(1280., 720.))
-                    .ok()
-                    .flatten()
-                    .is_some_and(
-                        |u| u == Boxing::new(Vec2::new(320., 180.), Vec2::new(1280., 720.))
-                    ),
-                "Testing against a non-integer (but square) scaling failed! (720p -> 1080p)"
+            app.world_mut()
+                .resource_mut::<CameraBoxingSettings>()
+                .min_content_size = UVec2::new(1920, 1080);
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::ZERO);
+            assert_eq!(viewport.physical_size, W720P);
+        }
+
+        #[test]
+        fn test_adjust_boxing_for() {
+            let mut app = App::new();
+
+            app.init_resource::<ManualTextureViews>();
+            app.init_resource::<Assets<Image>>();
+            app.init_resource::<CameraBoxingSettings>();
+            app.add_message::<BoxingError>();
+            app.add_message::<BoxingToggled>();
+            app.add_message::<CameraBoxed>();
+            app.add_message::<AdjustBoxingFor>();
+            app.world_mut().spawn((
+                Window {
+                    resolution: W720P.into(),
+                    ..Window::default()
+                },
+                PrimaryWindow,
+            ));
+            let spawn_camera = |app: &mut App| {
+                app.world_mut()
+                    .spawn((
+                        Camera::default(),
+                        RenderTarget::Window(WindowRef::Primary),
+                        CameraBox::StaticResolution {
+                            resolution: W360P,
+                            position: None,
+                            scale: ResolutionScaleMode::Exact,
+                        },
+                    ))
+                    .id()
+            };
+            let first_camera = spawn_camera(&mut app);
+            let second_camera = spawn_camera(&mut app);
+            app.add_systems(
+                First,
+                adjust_viewport_for.run_if(on_message::<AdjustBoxingFor>),
             );
 
-            // Test Output to imperfect scale
+            app.world_mut().write_message(AdjustBoxingFor(first_camera));
+            app.update();
             assert!(
-                calculate_boxing_imperfect(&Vec2::new(3840., 2160.), &Vec2::new(1920., 1080.))
-                    .is_ok_and(|u| u.is_none()),
-                "Testing against an integer scale resolution failed! (1080p -> 2160p)"
+                app.world()
+                    .get::<Camera>(first_camera)
+                    .unwrap()
+                    .viewport
+                    .is_some()
             );
-
             assert!(
-                calculate_boxing_imperfect(&Vec2::new(1280., 722.), &Vec2::new(640., 360.))
-                    .ok()
-                    .flatten()
-                    .is_some_and(|u| u == Boxing::new(Vec2::new(0., 1.), Vec2::new(1280., 720.))),
-                "Testing against minor increase to height in scaling failed! (360p -> 1280x722)"
+                app.world()
+                    .get::<Camera>(second_camera)
+                    .unwrap()
+                    .viewport
+                    .is_none()
             );
 
-            assert!(
-                calculate_boxing_imperfect(&Vec2::new(1282., 720.), &Vec2::new(640., 360.))
-                    .ok()
-                    .flatten()
-                    .is_some_and(|u| u == Boxing::new(Vec2::new(1., 0.), Vec2::new(1280., 720.))),
-                "Testing against minor increase to width in scaling failed! (360p -> 1282x720)"
-            );
+            app.world_mut()
+                .write_message(AdjustBoxingFor(second_camera));
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(second_camera)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(320, 180));
+            assert_eq!(viewport.physical_size, W360P);
+        }
 
-            assert!(
-                calculate_boxing_imperfect(&Vec2::new(320., 180.), &Vec2::new(640., 360.))
-                    .is_ok_and(|u| u.is_none()),
-                "Testing against downscaling failed! (360p -> 180p)"
+        #[test]
+        fn test_original_viewport_restored() {
+            let (mut app, camera_id) = setup_app(CameraBox::static_resolution(W360P), W720P.into());
+            app.add_systems(
+                First,
+                (
+                    store_original_viewports.before(adjust_viewport),
+                    restore_original_viewports.after(adjust_viewport),
+                ),
             );
+            app.world_mut()
+                .get_mut::<Camera>(camera_id)
+                .unwrap()
+                .viewport = Some(Viewport {
+                physical_position: UVec2::new(10, 10),
+                physical_size: W180P,
+                ..Viewport::default()
+            });
+            let viewport = |app: &App| {
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .clone()
+                    .unwrap()
+            };
 
-            assert!(
-                calculate_boxing_imperfect(&Vec2::new(330., 190.), &Vec2::new(640., 360.))
-                    .ok()
-                    .flatten()
-                    .is_some_and(
-                        |u| u == Boxing::new(Vec2::new(0., 2.1875), Vec2::new(330., 185.625))
-                    ),
-                "Testing against off downscaling failed! (360p -> (180p + 10))"
-            );
+            app.update();
+            assert_eq!(viewport(&app).physical_position, UVec2::new(320, 180));
+            assert_eq!(viewport(&app).physical_size, W360P);
 
-            assert!(
-                calculate_boxing_imperfect(&Vec2::new(320., 620.), &Vec2::new(320., 620.))
-                    .is_ok_and(|u| u.is_none()),
-                "Testing against Vertical Resolutions failed! (320x620 -> 320x620)"
-            );
+            app.world_mut().entity_mut(camera_id).remove::<CameraBox>();
+            app.update();
+            assert_eq!(viewport(&app).physical_position, UVec2::new(10, 10));
+            assert_eq!(viewport(&app).physical_size, W180P);
+            assert!(app.world().get::<OriginalViewport>(camera_id).is_none());
+        }
 
-            assert!(
-                calculate_boxing_imperfect(&Vec2::new(320., 620.), &Vec2::new(640., 360.))
-                    .ok()
-                    .flatten()
-                    .is_some_and(|u| u == Boxing::new(Vec2::new(0., 220.), Vec2::new(320., 180.))),
-                "Testing against Vertical Output to Widescreen Input failed! (360p -> 320x620)"
-            );
+        #[test]
+        fn test_aspect_epsilon() {
+            let (mut app, camera_id) =
+                setup_app(CameraBox::aspect_16_9(), UVec2::new(1366, 768).into());
+            let viewport = |app: &App| {
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .clone()
+            };
+            app.update();
+            assert!(viewport(&app).is_some());
 
-            assert!(
-                calculate_boxing_imperfect(&Vec2::new(1280., 720.), &Vec2::new(640., 480.))
-                    .ok()
-                    .flatten()
-                    .is_some_and(|u| u == Boxing::new(Vec2::new(320., 120.), Vec2::new(640., 480.))),
-                "Testing against 4:3 480p -> 16:9 720p failed!"
-            );
+            app.world_mut()
+                .resource_mut::<CameraBoxingSettings>()
+                .aspect_epsilon = Some(0.01);
+            app.update();
+            assert!(viewport(&app).is_none());
+
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(AspectTolerance(0.));
+            app.update();
+            assert!(viewport(&app).is_some());
+
+            // Every mode that boxes to an Aspect Ratio uses the same epsilon.
+            app.world_mut()
+                .entity_mut(camera_id)
+                .remove::<AspectTolerance>()
+                .insert(CameraBox::CutsceneLetterbox {
+                    video_size: UVec2::new(1920, 1080),
+                });
+            app.update();
+            assert!(viewport(&app).is_none());
+
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(CameraBox::AspectRatioWithMargin {
+                    aspect_ratio: AspectRatio::SIXTEEN_NINE,
+                    margin: UVec4::ZERO,
+                });
+            app.update();
+            assert!(viewport(&app).is_none());
+
+            app.world_mut()
+                .spawn((CameraBox::aspect_16_9(), NestedWithin(camera_id)));
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(CameraBox::FixedContentHeight { height: 0 });
+            app.update();
+            assert!(viewport(&app).is_none());
         }
 
         #[test]
-        fn test_calculate_boxing_perfect() {
-            assert!(
-                calculate_boxing_perfect(&Vec2::new(640., 360.), &Vec2::new(640., 360.))
-                    .is_ok_and(|u| u.is_none()),
-                "Testing against the same resolution failed! (360p -> 360p)",
+        fn test_even_dimensions() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: UVec2::new(641, 361),
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W720P.into(),
             );
-
-            // Test Output with Expected Boxing
-            assert!(
-                calculate_boxing_perfect(&Vec2::new(1920., 1080.), &Vec2::new(1280., 720.))
-                    .ok()
-                    .flatten()
-                    .is_some_and(
-                        |u| u == Boxing::new(Vec2::new(320., 180.), Vec2::new(1280., 720.))
-                    ),
-                "Testing against a non-integer (but square) scaling failed! (720p -> 1080p)"
+            app.world_mut()
+                .resource_mut::<CameraBoxingSettings>()
+                .even_dimensions = true;
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(320, 180));
+            assert_eq!(viewport.physical_size, W360P);
+            assert_eq!(
+                viewport.physical_position,
+                W720P - viewport.physical_position - viewport.physical_size
             );
 
-            // Test Output to perfect scale
-            assert!(
-                calculate_boxing_perfect(&Vec2::new(3840., 2160.), &Vec2::new(1920., 1080.))
-                    .is_ok_and(|u| u.is_none()),
-                "Testing against an integer scale resolution failed! (1080p -> 2160p)"
+            let (mut app, camera_id) = setup_app(
+                CameraBox::PillarBox {
+                    left: 10,
+                    right: 9,
+                    strict_pillarboxing: true,
+                    logical_bars: false,
+                },
+                W720P.into(),
             );
+            app.world_mut()
+                .resource_mut::<CameraBoxingSettings>()
+                .even_dimensions = true;
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(10, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(1260, 720));
+        }
 
-            assert!(
-                calculate_boxing_perfect(&Vec2::new(1280., 722.), &Vec2::new(640., 360.))
-                    .ok()
-                    .flatten()
-                    .is_some_and(|u| u == Boxing::new(Vec2::new(0., 1.), Vec2::new(1280., 720.))),
-                "Testing against minor increase to height in scaling failed! (360p -> 1280x722)"
-            );
+        #[test]
+        fn test_box_rounding() -> Result<()> {
+            for target in [
+                UVec2::new(1281, 722),
+                UVec2::new(1280, 723),
+                UVec2::new(1283, 721),
+                UVec2::new(1279, 725),
+            ] {
+                let (mut app, camera_id) = setup_app(
+                    CameraBox::StaticAspectRatio {
+                        aspect_ratio: AspectRatio::try_from(W360P.as_vec2())?,
+                        position: None,
+                        strict: false,
+                    },
+                    target.into(),
+                );
+                app.world_mut()
+                    .resource_mut::<CameraBoxingSettings>()
+                    .rounding = BoxRounding::SymmetricPad;
+                app.update();
+                let viewport = app
+                    .world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .to_owned()
+                    .viewport
+                    .unwrap();
+                let near_bars = viewport.physical_position;
+                let far_bars = target - viewport.physical_position - viewport.physical_size;
+                assert_eq!(near_bars + viewport.physical_size + far_bars, target);
+                assert!(near_bars.x.abs_diff(far_bars.x) <= 1, "{target}");
+                assert!(near_bars.y.abs_diff(far_bars.y) <= 1, "{target}");
+            }
 
-            assert!(
-                calculate_boxing_perfect(&Vec2::new(1282., 720.), &Vec2::new(640., 360.))
-                    .ok()
-                    .flatten()
-                    .is_some_and(|u| u == Boxing::new(Vec2::new(1., 0.), Vec2::new(1280., 720.))),
-                "Testing against minor increase to width in scaling failed! (360p -> 1282x720)"
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::try_from(W360P.as_vec2())?,
+                    position: None,
+                    strict: false,
+                },
+                UVec2::new(1281, 722).into(),
             );
+            app.world_mut()
+                .resource_mut::<CameraBoxingSettings>()
+                .rounding = BoxRounding::Round;
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::ZERO);
+            assert_eq!(viewport.physical_size, UVec2::new(1281, 721));
 
-            assert!(
-                calculate_boxing_perfect(&Vec2::new(320., 180.), &Vec2::new(640., 360.))
-                    .is_ok_and(|u| u.is_none()),
-                "Testing against downscaling failed! (360p -> 180p)"
-            );
+            Ok(())
+        }
 
-            assert!(
-                calculate_boxing_perfect(&Vec2::new(330., 190.), &Vec2::new(640., 360.))
-                    .ok()
-                    .flatten()
-                    .is_some_and(|u| u == Boxing::new(Vec2::new(5., 5.), Vec2::new(320., 180.))),
-                "Testing against off downscaling failed! (360p -> (180p + 10))"
+        #[test]
+        fn test_resolution_focus_anchor() -> Result<()> {
+            let focus = Vec2::new(0.25, 0.5);
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W720P.into(),
             );
-
-            assert!(
-                calculate_boxing_perfect(&Vec2::new(320., 620.), &Vec2::new(320., 620.))
-                    .is_ok_and(|u| u.is_none()),
-                "Testing against Vertical Resolutions failed! (320x620 -> 320x620)"
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(BoxAnchor::Focus(focus));
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(160, 180));
+            assert_eq!(
+                viewport.physical_position.as_vec2() + viewport.physical_size.as_vec2() * focus,
+                W720P.as_vec2() * focus
             );
 
-            assert!(
-                calculate_boxing_perfect(&Vec2::new(320., 620.), &Vec2::new(640., 360.))
-                    .ok()
-                    .flatten()
-                    .is_some_and(|u| u == Boxing::new(Vec2::new(0., 220.), Vec2::new(320., 180.))),
-                "Testing against Vertical Output to Widescreen Input failed! (360p -> 320x620)"
+            let window = app
+                .world_mut()
+                .query_filtered::<Entity, With<PrimaryWindow>>()
+                .single(app.world())?;
+            app.world_mut()
+                .get_mut::<Window>(window)
+                .unwrap()
+                .resolution
+                .set_physical_resolution(1920, 1080);
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(320, 360));
+            assert_eq!(
+                viewport.physical_position.as_vec2() + viewport.physical_size.as_vec2() * focus,
+                Vec2::new(1920., 1080.) * focus
             );
 
-            assert!(
-                calculate_boxing_perfect(&Vec2::new(1280., 720.), &Vec2::new(640., 480.))
-                    .ok()
-                    .flatten()
-                    .is_some_and(|u| u == Boxing::new(Vec2::new(320., 120.), Vec2::new(640., 480.))),
-                "Testing against 4:3 480p -> 16:9 720p failed!"
-            );
+            Ok(())
         }
 
         #[test]
-        fn test_calculate_letterbox() {
-            let inputs: [(u32, u32); 6] =
-                [(100, 100), (100, 0), (100, 50), (50, 100), (0, 0), (0, 100)];
-            let physical_size = Vec2::new(640., 360.);
-            let outputs: [_; 6] = [
-                Boxing::new(Vec2::new(0., 100.), Vec2::new(640., 160.)),
-                Boxing::new(Vec2::new(0., 100.), Vec2::new(640., 260.)),
-                Boxing::new(Vec2::new(0., 100.), Vec2::new(640., 210.)),
-                Boxing::new(Vec2::new(0., 50.), Vec2::new(640., 210.)),
-                Boxing::new(Vec2::new(0., 0.), Vec2::new(640., 360.)),
-                Boxing::new(Vec2::new(0., 0.), Vec2::new(640., 260.)),
-            ];
-            for (i, input) in inputs.iter().enumerate() {
-                assert_eq!(
-                    calculate_letterbox(&physical_size, (&input.0, &input.1)),
-                    outputs[i]
-                );
-            }
+        fn test_anchored_offset() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W720P.into(),
+            );
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(AnchoredOffset {
+                    anchor: BoxAnchor::TopRight,
+                    offset: IVec2::new(-20, 20),
+                });
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(620, 20));
+            assert_eq!(viewport.physical_size, W360P);
+
+            // The output is kept within the render target.
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(AnchoredOffset {
+                    anchor: BoxAnchor::TopRight,
+                    offset: IVec2::new(20, -20),
+                });
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(640, 0));
+            assert_eq!(viewport.physical_size, W360P);
         }
+
         #[test]
-        fn test_calculate_pillarbox() {
-            let inputs: [(u32, u32); 6] =
-                [(100, 100), (100, 0), (100, 50), (50, 100), (0, 0), (0, 100)];
-            let physical_size = Vec2::new(640., 360.);
-            let outputs = [
-                Boxing::new(Vec2::new(100., 0.), Vec2::new(440., 360.)),
-                Boxing::new(Vec2::new(100., 0.), Vec2::new(540., 360.)),
-                Boxing::new(Vec2::new(100., 0.), Vec2::new(490., 360.)),
-                Boxing::new(Vec2::new(50., 0.), Vec2::new(490., 360.)),
-                Boxing::new(Vec2::new(0., 0.), Vec2::new(640., 360.)),
-                Boxing::new(Vec2::new(0., 0.), Vec2::new(540., 360.)),
-            ];
-            for (i, input) in inputs.iter().enumerate() {
-                assert_eq!(
-                    calculate_pillarbox(&physical_size, (&input.0, &input.1)),
-                    outputs[i]
-                );
-            }
+        fn test_cutscene_letterbox() {
+            let original = CameraBox::StaticResolution {
+                resolution: W720P,
+                position: None,
+                scale: ResolutionScaleMode::Exact,
+            };
+            let (mut app, camera_id) = setup_app(original.clone(), W720P.into());
+            app.init_resource::<Time>();
+            app.add_systems(First, update_temporary_boxes.before(adjust_viewport));
+
+            // A 2.35:1 video
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(TemporaryBox::cutscene(
+                    UVec2::new(1920, 817),
+                    Duration::from_secs(10),
+                ));
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 87));
+            assert_eq!(viewport.physical_size, UVec2::new(1280, 545));
+
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs(10));
+            app.update();
+            assert_eq!(app.world().get::<CameraBox>(camera_id), Some(&original));
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            assert!(viewport.is_none());
         }
 
         #[test]
-        fn test_calculate_windowbox() {
-            let inputs: [[(&u32, &u32); 2]; 8] = [
-                [(&0, &0), (&0, &0)],     //Test Noboxing
-                [(&100, &100), (&0, &0)], //Test Letterboxing
-                [(&0, &0), (&100, &100)], //Test Pillarboxing
-                [(&50, &0), (&50, &0)],   //Test Boxing Bottom Left
-                [(&0, &50), (&0, &50)],   //Test Bottom Boxing.
-                [(&50, &50), (&50, &50)], //Test Full Boxing
-                [(&50, &0), (&0, &50)],   //Test Opp Boxing
-                [(&0, &50), (&50, &0)],   //Test Opp Boxing 2
-            ];
-            let physical_size = Vec2::new(640., 360.);
-
-            let outputs: [Boxing; 8] = [
-                Boxing::new(Vec2::new(0., 0.), physical_size),
-                Boxing::new(Vec2::new(0., 100.), Vec2::new(640., 160.)),
-                Boxing::new(Vec2::new(100., 0.), Vec2::new(440., 360.)),
-                Boxing::new(Vec2::new(50., 50.), Vec2::new(590., 310.)),
-                Boxing::new(Vec2::new(0., 0.), Vec2::new(590., 310.)),
-                Boxing::new(Vec2::new(50., 50.), Vec2::new(540., 260.)),
-                Boxing::new(Vec2::new(0., 50.), Vec2::new(590., 310.)),
-                Boxing::new(Vec2::new(50., 0.), Vec2::new(590., 310.)),
-            ];
+        fn test_iris_reveal() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W720P.into(),
+            );
+            app.init_resource::<Time>();
+            app.add_message::<AdjustBoxingFor>();
+            app.add_systems(First, update_iris_reveals.before(adjust_viewport));
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(IrisReveal::new(
+                    0.25,
+                    Duration::from_secs(1),
+                    EaseFunction::Linear,
+                ));
 
-            for (i, input) in inputs.into_iter().enumerate() {
-                assert_eq!(calculate_windowbox(&physical_size, input), outputs[i],);
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(560, 315));
+            assert_eq!(viewport.physical_size, UVec2::new(160, 90));
+
+            let mut last_size = viewport.physical_size;
+            for _ in 0..4 {
+                app.world_mut()
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_millis(250));
+                app.update();
+                let viewport = app
+                    .world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .to_owned()
+                    .viewport
+                    .unwrap();
+                assert!(viewport.physical_size.cmpgt(last_size).all());
+                last_size = viewport.physical_size;
             }
+
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(320, 180));
+            assert_eq!(viewport.physical_size, W360P);
+            assert!(app.world().get::<IrisReveal>(camera_id).is_none());
         }
-    }
 
-    mod systems {
-        use super::*;
-        use bevy_asset::AssetId;
-        use bevy_camera::RenderTarget;
-        use bevy_window::{WindowRef, WindowResolution};
+        #[test]
+        fn test_camera_box_transition() {
+            let (mut app, camera_id) = setup_app(CameraBox::static_resolution(W360P), W720P.into());
+            app.init_resource::<Time>();
+            app.add_message::<AdjustBoxingFor>();
+            app.add_systems(First, update_camera_box_transitions.before(adjust_viewport));
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(CameraBoxTransition::new(
+                    Duration::from_secs(1),
+                    EaseFunction::Linear,
+                ));
+
+            // The first boxing is used straight away.
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(320, 180));
+            assert_eq!(viewport.physical_size, W360P);
 
-        const W360P: UVec2 = UVec2::new(640, 360);
-        const W720P: UVec2 = UVec2::new(1280, 720);
-        const W180P: UVec2 = UVec2::new(320, 180);
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(CameraBox::static_resolution(W180P));
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_size, W360P);
 
-        fn setup_app(camerabox: CameraBox, window_resolution: WindowResolution) -> (App, Entity) {
-            let mut app = App::new();
+            for _ in 0..3 {
+                app.world_mut()
+                    .resource_mut::<Time>()
+                    .advance_by(Duration::from_millis(250));
+                app.update();
+                let viewport = app
+                    .world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .to_owned()
+                    .viewport
+                    .unwrap();
+                assert!(viewport.physical_size.cmplt(W360P).all());
+                assert!(viewport.physical_size.cmpgt(W180P).all());
+                assert!(viewport.physical_position.cmpgt(UVec2::new(320, 180)).all());
+                assert!(viewport.physical_position.cmplt(UVec2::new(480, 270)).all());
+            }
 
-            app.init_resource::<ManualTextureViews>();
-            app.init_resource::<Assets<Image>>();
-            app.world_mut().spawn((
-                Window {
-                    resolution: window_resolution,
-                    ..Window::default()
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(250));
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(480, 270));
+            assert_eq!(viewport.physical_size, W180P);
+            assert!(
+                app.world()
+                    .get::<CameraBoxTransition>(camera_id)
+                    .unwrap()
+                    .is_finished()
+            );
+        }
+
+        #[test]
+        fn test_boxing_state_round_trip() {
+            let (mut app, camera_id) = setup_app(CameraBox::letterbox(10, 20), W360P.into());
+            app.add_message::<AdjustBoxing>();
+            app.world_mut().entity_mut(camera_id).insert((
+                BoxAnchor::TopLeft,
+                CameraBoxEnabled(false),
+                ViewportShake {
+                    offset: IVec2::new(2, 3),
                 },
-                PrimaryWindow,
+                AspectTolerance(0.01),
+                MinViewportSize {
+                    size: UVec2::new(32, 32),
+                    ..MinViewportSize::default()
+                },
+                CameraBoxGroup(1),
+                InactiveBox(Some(CameraBox::letterbox(1, 1))),
+                PendingTargetSize(Some(W720P)),
+                OriginalViewport(Some(Viewport {
+                    physical_position: UVec2::new(1, 2),
+                    physical_size: UVec2::new(3, 4),
+                    ..Viewport::default()
+                })),
             ));
-            let camera_id = app
-                .world_mut()
-                .spawn((
-                    Camera {
-                        viewport: None,
-                        is_active: true,
-                        ..Camera::default()
-                    },
-                    RenderTarget::Window(WindowRef::Primary),
-                    camerabox,
+            let state = capture_boxing_state(app.world(), camera_id);
+            assert_eq!(state.camera_box, Some(CameraBox::letterbox(10, 20)));
+            assert_eq!(state.anchor, Some(BoxAnchor::TopLeft));
+            assert_eq!(state.anchored_offset, None);
+            assert_eq!(state.aspect_tolerance, Some(AspectTolerance(0.01)));
+            assert_eq!(state.group, Some(CameraBoxGroup(1)));
+            assert_eq!(
+                state.pending_target_size,
+                Some(PendingTargetSize(Some(W720P)))
+            );
+
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert((
+                    CameraBox::pillarbox(5, 5),
+                    BoxAnchor::Center,
+                    CameraBoxGroup(2),
                 ))
-                .id();
-            app.add_systems(First, adjust_viewport);
-            (app, camera_id)
+                .remove::<(
+                    CameraBoxEnabled,
+                    ViewportShake,
+                    AspectTolerance,
+                    MinViewportSize,
+                    InactiveBox,
+                    PendingTargetSize,
+                    OriginalViewport,
+                )>();
+            let mut reader = app
+                .world()
+                .resource::<Messages<AdjustBoxing>>()
+                .get_cursor_current();
+            restore_boxing_state(app.world_mut(), camera_id, state.clone());
+
+            assert_eq!(capture_boxing_state(app.world(), camera_id), state);
+            assert!(
+                reader
+                    .read(app.world().resource::<Messages<AdjustBoxing>>())
+                    .next()
+                    .is_some()
+            );
         }
 
         #[test]
-        fn test_basic_windowboxing() {
-            let (mut app, camera_id) = setup_app(
-                CameraBox::WindowBox {
-                    left: 10,
-                    right: 10,
-                    top: 10,
-                    bottom: 10,
-                    strict_windowboxing: false,
-                },
-                W360P.into(),
+        fn test_min_viewport_size() {
+            let (mut app, camera_id) = setup_app(CameraBox::letterbox(20, 20), W180P.into());
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_size, UVec2::new(320, 140));
+
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(MinViewportSize {
+                    size: UVec2::new(160, 160),
+                    behavior: MinViewportBehavior::Disable,
+                });
+            app.update();
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_none()
             );
+
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(MinViewportSize {
+                    size: UVec2::new(160, 160),
+                    behavior: MinViewportBehavior::Clamp,
+                });
             app.update();
             let viewport = app
                 .world()
@@ -1131,18 +8222,32 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(10, 10));
-            assert_eq!(viewport.physical_size, UVec2::new(620, 340));
+            assert_eq!(viewport.physical_position, UVec2::new(0, 10));
+            assert_eq!(viewport.physical_size, UVec2::new(320, 160));
+
+            // Outputs that are already large enough are left alone.
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(MinViewportSize {
+                    size: UVec2::new(16, 16),
+                    behavior: MinViewportBehavior::Disable,
+                });
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_size, UVec2::new(320, 140));
+        }
 
+        #[test]
+        fn test_max_bar_size() -> Result<()> {
             let (mut app, camera_id) = setup_app(
-                CameraBox::WindowBox {
-                    left: 10,
-                    right: 10,
-                    top: 10,
-                    bottom: 10,
-                    strict_windowboxing: true,
-                },
-                W360P.into(),
+                CameraBox::static_aspect_ratio(AspectRatio::try_new(16., 9.)?),
+                UVec2::new(1280, 1120).into(),
             );
             app.update();
             let viewport = app
@@ -1152,38 +8257,61 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(10, 10));
-            assert_eq!(viewport.physical_size, UVec2::new(620, 340));
+            assert_eq!(viewport.physical_position, UVec2::new(0, 200));
+            assert_eq!(viewport.physical_size, UVec2::new(1280, 720));
 
-            let (mut app, camera_id) = setup_app(
-                CameraBox::WindowBox {
-                    left: 650,
-                    right: 0,
-                    top: 370,
-                    bottom: 0,
-                    strict_windowboxing: true,
-                },
-                W360P.into(),
-            );
+            app.world_mut()
+                .resource_mut::<CameraBoxingSettings>()
+                .max_bar_size = Some(100);
             app.update();
             let viewport = app
                 .world()
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport;
-            assert!(viewport.is_none());
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 100));
+            assert_eq!(viewport.physical_size, UVec2::new(1280, 920));
+
+            // Bars that are already thin enough are left alone.
+            app.world_mut()
+                .resource_mut::<CameraBoxingSettings>()
+                .max_bar_size = Some(300);
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 200));
+            assert_eq!(viewport.physical_size, UVec2::new(1280, 720));
+            Ok(())
+        }
 
+        #[test]
+        fn test_layout_editing() {
             let (mut app, camera_id) = setup_app(
-                CameraBox::WindowBox {
-                    left: 650,
-                    right: 0,
-                    top: 370,
-                    bottom: 0,
-                    strict_windowboxing: false,
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
                 },
-                W360P.into(),
+                W720P.into(),
             );
+            app.insert_resource(LayoutEditing(true));
+            let edited = Viewport {
+                physical_position: UVec2::new(10, 20),
+                physical_size: UVec2::new(100, 100),
+                ..Viewport::default()
+            };
+            app.world_mut()
+                .get_mut::<Camera>(camera_id)
+                .unwrap()
+                .viewport = Some(edited.clone());
+            app.update();
             app.update();
             let viewport = app
                 .world()
@@ -1192,20 +8320,10 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(322, 182));
-            assert_eq!(viewport.physical_size, UVec2::new(320, 180));
-        }
+            assert_eq!(viewport.physical_position, edited.physical_position);
+            assert_eq!(viewport.physical_size, edited.physical_size);
 
-        #[test]
-        fn test_basic_pillarboxing() {
-            let (mut app, camera_id) = setup_app(
-                CameraBox::PillarBox {
-                    left: 2,
-                    right: 2,
-                    strict_pillarboxing: false,
-                },
-                W360P.into(),
-            );
+            app.insert_resource(LayoutEditing(false));
             app.update();
             let viewport = app
                 .world()
@@ -1214,17 +8332,48 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(2, 0));
-            assert_eq!(viewport.physical_size, UVec2::new(636, 360));
+            assert_eq!(viewport.physical_position, UVec2::new(320, 180));
+            assert_eq!(viewport.physical_size, W360P);
+        }
 
+        #[test]
+        fn test_camera_box_enabled() {
             let (mut app, camera_id) = setup_app(
-                CameraBox::PillarBox {
-                    left: 5,
-                    right: 0,
-                    strict_pillarboxing: false,
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
                 },
-                W360P.into(),
+                W720P.into(),
+            );
+            app.update();
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_some()
+            );
+
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(CameraBoxEnabled(false));
+            app.update();
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_none()
             );
+            assert!(app.world().get::<CameraBox>(camera_id).is_some());
+            let computed = app.world().get::<ComputedBoxing>(camera_id).unwrap();
+            assert_eq!(*computed, ComputedBoxing::default());
+            assert!(!computed.is_boxed());
+
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(CameraBoxEnabled(true));
             app.update();
             let viewport = app
                 .world()
@@ -1233,17 +8382,216 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(5, 0));
-            assert_eq!(viewport.physical_size, UVec2::new(635, 360));
+            assert_eq!(viewport.physical_size, W360P);
+            assert!(
+                app.world()
+                    .get::<ComputedBoxing>(camera_id)
+                    .unwrap()
+                    .is_boxed()
+            );
+        }
+
+        #[test]
+        fn test_despawn_boxed_camera() {
+            use bevy_ecs::schedule::ScheduleBuildSettings;
 
+            let (mut app, camera_id) = setup_app(CameraBox::static_resolution(W360P), W720P.into());
+            app.add_systems(
+                First,
+                (spawn_box_bars, sync_box_bars, remove_box_bars)
+                    .chain()
+                    .after(adjust_viewport),
+            );
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(CameraBoxBars::new(Color::BLACK));
+            app.update();
+            let mut bar_cameras = app.world_mut().query::<&BoxBarsFor>();
+            assert_eq!(bar_cameras.iter(app.world()).count(), 1);
+
+            // The despawn is applied in the same batch of commands as the plugin's own commands for
+            // the camera (and before them), like it would be if another system despawned it.
+            app.edit_schedule(First, |schedule| {
+                schedule.set_build_settings(ScheduleBuildSettings {
+                    auto_insert_apply_deferred: false,
+                    ..ScheduleBuildSettings::default()
+                });
+            });
+            app.add_systems(
+                First,
+                (
+                    (move |mut commands: Commands| {
+                        commands.entity(camera_id).try_despawn();
+                    })
+                    .before(store_original_viewports),
+                    store_original_viewports.before(adjust_viewport),
+                ),
+            );
+            app.update();
+            app.update();
+
+            assert!(app.world().get_entity(camera_id).is_err());
+            assert_eq!(bar_cameras.iter(app.world()).count(), 0);
+        }
+
+        #[test]
+        fn test_box_bars() {
             let (mut app, camera_id) = setup_app(
                 CameraBox::PillarBox {
-                    left: 0,
-                    right: 5,
+                    left: 80,
+                    right: 80,
                     strict_pillarboxing: false,
+                    logical_bars: false,
                 },
                 W360P.into(),
             );
+            app.add_systems(
+                First,
+                (spawn_box_bars, sync_box_bars, remove_box_bars)
+                    .chain()
+                    .after(adjust_viewport),
+            );
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(CameraBoxBars::new(Color::BLACK));
+            app.update();
+
+            let mut bar_cameras = app
+                .world_mut()
+                .query::<(Entity, &BoxBarsFor, &Camera, &RenderTarget)>();
+            let (bar_camera, bars_for, camera, target) = bar_cameras.single(app.world()).unwrap();
+            assert_eq!(bars_for.camera, camera_id);
+            assert_eq!(camera.order, -1);
+            assert!(camera.viewport.is_none());
+            assert!(matches!(
+                camera.clear_color,
+                ClearColorConfig::Custom(color) if color == Color::BLACK
+            ));
+            assert_eq!(target, &RenderTarget::Window(WindowRef::Primary));
+            assert!(matches!(
+                app.world().get::<Camera>(camera_id).unwrap().clear_color,
+                ClearColorConfig::Default
+            ));
+
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(CameraBoxBars::new(Color::WHITE));
+            app.update();
+            assert!(matches!(
+                app.world().get::<Camera>(bar_camera).unwrap().clear_color,
+                ClearColorConfig::Custom(color) if color == Color::WHITE
+            ));
+
+            app.world_mut()
+                .entity_mut(camera_id)
+                .remove::<CameraBoxBars>();
+            app.update();
+            assert!(app.world().get_entity(bar_camera).is_err());
+            assert!(matches!(
+                app.world().get::<Camera>(camera_id).unwrap().clear_color,
+                ClearColorConfig::Default
+            ));
+        }
+
+        #[test]
+        fn test_box_bars_below_other_cameras() {
+            let (mut app, world_camera) =
+                setup_app(CameraBox::static_resolution(W360P), W720P.into());
+            app.add_systems(
+                First,
+                (spawn_box_bars, sync_box_bars, remove_box_bars)
+                    .chain()
+                    .after(adjust_viewport),
+            );
+            let ui_camera = app
+                .world_mut()
+                .spawn((
+                    Camera {
+                        order: 1,
+                        clear_color: ClearColorConfig::None,
+                        ..Camera::default()
+                    },
+                    RenderTarget::Window(WindowRef::Primary),
+                    CameraBox::static_resolution(W360P),
+                    CameraBoxBars::new(Color::BLACK),
+                ))
+                .id();
+            app.world_mut()
+                .entity_mut(world_camera)
+                .insert(CameraBoxBars::new(Color::WHITE));
+            app.update();
+            app.update();
+
+            let mut bar_cameras = app.world_mut().query::<(&BoxBarsFor, &Camera)>();
+            let mut bar_order = |app: &App, camera: Entity| {
+                bar_cameras
+                    .iter(app.world())
+                    .find(|(bars_for, _)| bars_for.camera == camera)
+                    .unwrap()
+                    .1
+                    .order
+            };
+            let world_bars = bar_order(&app, world_camera);
+            let ui_bars = bar_order(&app, ui_camera);
+
+            // Neither bar camera can clear over the world camera (or each other).
+            assert!(world_bars < 0);
+            assert!(ui_bars < 0);
+            assert_ne!(world_bars, ui_bars);
+            assert_eq!(app.world().get::<Camera>(world_camera).unwrap().order, 0);
+            assert!(matches!(
+                app.world().get::<Camera>(world_camera).unwrap().clear_color,
+                ClearColorConfig::Default
+            ));
+            assert!(matches!(
+                app.world().get::<Camera>(ui_camera).unwrap().clear_color,
+                ClearColorConfig::None
+            ));
+        }
+
+        #[test]
+        fn test_box_bars_order() {
+            let (mut app, camera_id) = setup_app(CameraBox::static_resolution(W360P), W720P.into());
+            app.add_systems(
+                First,
+                (spawn_box_bars, sync_box_bars, remove_box_bars)
+                    .chain()
+                    .after(adjust_viewport),
+            );
+            app.world_mut().entity_mut(camera_id).insert(CameraBoxBars {
+                color: Color::BLACK,
+                order: Some(5),
+            });
+            app.update();
+            app.update();
+
+            let mut bar_cameras = app.world_mut().query::<(&BoxBarsFor, &Camera)>();
+            let (_, camera) = bar_cameras.single(app.world()).unwrap();
+            assert_eq!(camera.order, 5);
+
+            app.world_mut()
+                .get_mut::<CameraBoxBars>(camera_id)
+                .unwrap()
+                .order = None;
+            app.update();
+            let (_, camera) = bar_cameras.single(app.world()).unwrap();
+            assert_eq!(camera.order, -1);
+        }
+
+        #[test]
+        fn test_viewport_shake() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                },
+                W720P.into(),
+            );
+            app.add_systems(First, apply_viewport_shake.after(adjust_viewport));
+            app.world_mut().entity_mut(camera_id).insert(ViewportShake {
+                offset: IVec2::new(5, -3),
+            });
             app.update();
             let viewport = app
                 .world()
@@ -1252,17 +8600,13 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(0, 0));
-            assert_eq!(viewport.physical_size, UVec2::new(635, 360));
+            assert_eq!(viewport.physical_position, UVec2::new(325, 177));
+            assert_eq!(viewport.physical_size, W360P);
 
-            let (mut app, camera_id) = setup_app(
-                CameraBox::PillarBox {
-                    left: 5,
-                    right: 10,
-                    strict_pillarboxing: false,
-                },
-                W360P.into(),
-            );
+            // The viewport is kept within the render target.
+            app.world_mut().entity_mut(camera_id).insert(ViewportShake {
+                offset: IVec2::new(-500, 500),
+            });
             app.update();
             let viewport = app
                 .world()
@@ -1271,17 +8615,11 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(5, 0));
-            assert_eq!(viewport.physical_size, UVec2::new(625, 360));
+            assert_eq!(viewport.physical_position, UVec2::new(0, 360));
 
-            let (mut app, camera_id) = setup_app(
-                CameraBox::PillarBox {
-                    left: 10,
-                    right: 5,
-                    strict_pillarboxing: false,
-                },
-                W360P.into(),
-            );
+            app.world_mut()
+                .entity_mut(camera_id)
+                .remove::<ViewportShake>();
             app.update();
             let viewport = app
                 .world()
@@ -1290,17 +8628,50 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(10, 0));
-            assert_eq!(viewport.physical_size, UVec2::new(625, 360));
+            assert_eq!(viewport.physical_position, UVec2::new(320, 180));
+        }
 
-            let (mut app, camera_id) = setup_app(
-                CameraBox::PillarBox {
-                    left: 640,
-                    right: 0,
-                    strict_pillarboxing: false,
+        #[test]
+        fn test_late_window() {
+            let mut app = App::new();
+            app.init_resource::<ManualTextureViews>();
+            app.init_resource::<Assets<Image>>();
+            app.add_message::<AssetEvent<Image>>();
+            app.add_plugins(CameraBoxingPlugin::default());
+            app.world_mut().spawn((
+                Window {
+                    resolution: W360P.into(),
+                    ..Window::default()
                 },
-                W360P.into(),
+                PrimaryWindow,
+            ));
+            app.update();
+
+            // The camera targets a window that doesn't exist until after the camera's boxing has
+            // been calculated.
+            let window_id = app.world_mut().spawn_empty().id();
+            let camera_id = app
+                .world_mut()
+                .spawn((
+                    Camera::default(),
+                    RenderTarget::Window(WindowRef::Entity(window_id)),
+                    CameraBox::static_resolution(W360P),
+                ))
+                .id();
+            app.update();
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_none()
             );
+
+            app.world_mut().entity_mut(window_id).insert(Window {
+                resolution: W720P.into(),
+                resizable: false,
+                ..Window::default()
+            });
             app.update();
             let viewport = app
                 .world()
@@ -1309,18 +8680,96 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(320, 0));
-            assert_eq!(viewport.physical_size, UVec2::from(W180P).with_y(360));
+            assert_eq!(viewport.physical_position, UVec2::new(320, 180));
+            assert_eq!(viewport.physical_size, W360P);
+        }
 
-            let (mut app, camera_id) = setup_app(
-                CameraBox::PillarBox {
-                    left: 2,
-                    right: 2,
-                    strict_pillarboxing: true,
+        #[test]
+        fn test_inactive_box() {
+            let mut app = App::new();
+            app.init_resource::<ManualTextureViews>();
+            app.init_resource::<Assets<Image>>();
+            app.add_message::<AssetEvent<Image>>();
+            app.add_plugins(CameraBoxingPlugin::default());
+            app.world_mut().spawn((
+                Window {
+                    resolution: W720P.into(),
+                    ..Window::default()
                 },
-                W360P.into(),
+                PrimaryWindow,
+            ));
+            let camera_id = app
+                .world_mut()
+                .spawn((
+                    Camera::default(),
+                    RenderTarget::Window(WindowRef::Primary),
+                    CameraBox::static_resolution(W360P),
+                    InactiveBox(None),
+                ))
+                .id();
+            let viewport = |app: &App| {
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .clone()
+                    .map(|viewport| (viewport.physical_position, viewport.physical_size))
+            };
+            let set_active = |app: &mut App, is_active: bool| {
+                app.world_mut()
+                    .get_mut::<Camera>(camera_id)
+                    .unwrap()
+                    .is_active = is_active;
+            };
+            app.update();
+            assert_eq!(viewport(&app), Some((UVec2::new(320, 180), W360P)));
+
+            set_active(&mut app, false);
+            app.update();
+            assert_eq!(viewport(&app), None);
+
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(InactiveBox(Some(CameraBox::letterbox(60, 60))));
+            app.update();
+            assert_eq!(
+                viewport(&app),
+                Some((UVec2::new(0, 60), UVec2::new(1280, 600)))
             );
+
+            set_active(&mut app, true);
+            app.update();
+            assert_eq!(viewport(&app), Some((UVec2::new(320, 180), W360P)));
+        }
+
+        #[test]
+        fn test_pixel_art_plugin() {
+            let mut app = App::new();
+            app.init_resource::<ManualTextureViews>();
+            app.init_resource::<Assets<Image>>();
+            app.add_message::<AssetEvent<Image>>();
+            app.add_plugins(CameraBoxingPlugin::pixel_art(W180P));
+            app.world_mut().spawn((
+                Window {
+                    resolution: UVec2::new(1000, 600).into(),
+                    ..Window::default()
+                },
+                PrimaryWindow,
+            ));
+            let camera_id = app
+                .world_mut()
+                .spawn((Camera::default(), RenderTarget::Window(WindowRef::Primary)))
+                .id();
             app.update();
+            app.update();
+
+            assert!(matches!(
+                app.world().get::<CameraBox>(camera_id),
+                Some(CameraBox::ResolutionIntegerScale {
+                    allow_imperfect_downscaled_boxing: false,
+                    ..
+                })
+            ));
             let viewport = app
                 .world()
                 .get::<Camera>(camera_id)
@@ -1328,14 +8777,20 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(2, 0));
-            assert_eq!(viewport.physical_size, UVec2::new(636, 360));
+            assert_eq!(viewport.physical_position, UVec2::new(20, 30));
+            assert_eq!(viewport.physical_size, UVec2::new(960, 540));
+            let computed = app.world().get::<ComputedBoxing>(camera_id).unwrap();
+            assert_eq!(computed.boxing_offset, computed.boxing_offset.floor());
+        }
 
+        #[test]
+        fn test_pillarbox_non_strict_scales() {
             let (mut app, camera_id) = setup_app(
                 CameraBox::PillarBox {
-                    left: 5,
+                    left: 640,
                     right: 0,
-                    strict_pillarboxing: true,
+                    strict_pillarboxing: false,
+                    logical_bars: false,
                 },
                 W360P.into(),
             );
@@ -1347,14 +8802,18 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(5, 0));
-            assert_eq!(viewport.physical_size, UVec2::new(635, 360));
+            assert_eq!(viewport.physical_position, UVec2::new(320, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(320, 360));
+        }
 
+        #[test]
+        fn test_basic_aspect_ratio() -> Result<()> {
+            let desired_aspect_ratio = AspectRatio::try_from(W720P.as_vec2())?;
             let (mut app, camera_id) = setup_app(
-                CameraBox::PillarBox {
-                    left: 0,
-                    right: 5,
-                    strict_pillarboxing: true,
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: desired_aspect_ratio,
+                    position: None,
+                    strict: false,
                 },
                 W360P.into(),
             );
@@ -1364,18 +8823,17 @@ mod tests {
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport
-                .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(0, 0));
-            assert_eq!(viewport.physical_size, UVec2::new(635, 360));
+                .viewport;
+            assert!(viewport.is_none());
 
+            let desired_aspect_ratio = AspectRatio::try_new(640., 480.)?;
             let (mut app, camera_id) = setup_app(
-                CameraBox::PillarBox {
-                    left: 5,
-                    right: 10,
-                    strict_pillarboxing: true,
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: desired_aspect_ratio,
+                    position: None,
+                    strict: false,
                 },
-                W360P.into(),
+                W720P.into(),
             );
             app.update();
             let viewport = app
@@ -1385,14 +8843,15 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(5, 0));
-            assert_eq!(viewport.physical_size, UVec2::new(625, 360));
+            assert_eq!(viewport.physical_position, UVec2::new(160, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(960, 720));
 
+            let desired_aspect_ratio = AspectRatio::try_from(W720P.as_vec2())?;
             let (mut app, camera_id) = setup_app(
-                CameraBox::PillarBox {
-                    left: 10,
-                    right: 5,
-                    strict_pillarboxing: true,
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: desired_aspect_ratio,
+                    position: Some((1, 0).into()),
+                    strict: false,
                 },
                 W360P.into(),
             );
@@ -1402,19 +8861,32 @@ mod tests {
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport
-                .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(10, 0));
-            assert_eq!(viewport.physical_size, UVec2::new(625, 360));
+                .viewport;
+            assert!(viewport.is_none());
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_on_no_box_behavior() {
+            let custom_viewport = Viewport {
+                physical_position: UVec2::new(10, 10),
+                physical_size: W180P,
+                ..Viewport::default()
+            };
 
             let (mut app, camera_id) = setup_app(
-                CameraBox::PillarBox {
-                    left: 640,
-                    right: 0,
-                    strict_pillarboxing: true,
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
                 },
                 W360P.into(),
             );
+            app.world_mut()
+                .get_mut::<Camera>(camera_id)
+                .unwrap()
+                .viewport = Some(custom_viewport.clone());
             app.update();
             let viewport = app
                 .world()
@@ -1423,18 +8895,22 @@ mod tests {
                 .to_owned()
                 .viewport;
             assert!(viewport.is_none());
-        }
 
-        #[test]
-        fn test_basic_letterboxing() {
             let (mut app, camera_id) = setup_app(
-                CameraBox::LetterBox {
-                    top: 2,
-                    bottom: 2,
-                    strict_letterboxing: true,
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
                 },
                 W360P.into(),
             );
+            app.world_mut()
+                .resource_mut::<CameraBoxingSettings>()
+                .on_no_box = NoBoxBehavior::LeaveViewport;
+            app.world_mut()
+                .get_mut::<Camera>(camera_id)
+                .unwrap()
+                .viewport = Some(custom_viewport);
             app.update();
             let viewport = app
                 .world()
@@ -1443,92 +8919,192 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(0, 2));
-            assert_eq!(viewport.physical_size, UVec2::new(640, 356));
+            assert_eq!(viewport.physical_position, UVec2::new(10, 10));
+            assert_eq!(viewport.physical_size, W180P);
+        }
 
+        #[test]
+        fn test_unbox_in_exclusive_fullscreen() -> Result<()> {
             let (mut app, camera_id) = setup_app(
-                CameraBox::LetterBox {
-                    top: 5,
-                    bottom: 0,
-                    strict_letterboxing: true,
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
                 },
-                W360P.into(),
+                W720P.into(),
             );
+            let window = app
+                .world_mut()
+                .query_filtered::<Entity, With<PrimaryWindow>>()
+                .single(app.world())?;
+            app.world_mut().get_mut::<Window>(window).unwrap().mode =
+                WindowMode::Fullscreen(MonitorSelection::Primary, VideoModeSelection::Current);
             app.update();
             let viewport = app
                 .world()
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport
-                .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(0, 5));
-            assert_eq!(viewport.physical_size, UVec2::new(640, 355));
+                .viewport;
+            assert!(viewport.is_some());
 
-            let (mut app, camera_id) = setup_app(
-                CameraBox::LetterBox {
-                    top: 0,
-                    bottom: 5,
-                    strict_letterboxing: true,
-                },
-                W360P.into(),
-            );
+            app.world_mut()
+                .resource_mut::<CameraBoxingSettings>()
+                .unbox_in_exclusive_fullscreen = true;
             app.update();
             let viewport = app
                 .world()
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport
-                .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(0, 0));
-            assert_eq!(viewport.physical_size, UVec2::new(640, 355));
+                .viewport;
+            assert!(viewport.is_none());
 
+            Ok(())
+        }
+
+        #[test]
+        fn test_aspect_ratio_degenerate_target() -> Result<()> {
             let (mut app, camera_id) = setup_app(
-                CameraBox::LetterBox {
-                    top: 10,
-                    bottom: 5,
-                    strict_letterboxing: true,
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::try_new(640., 480.)?,
+                    position: None,
+                    strict: false,
                 },
                 W360P.into(),
             );
             app.update();
-            let viewport = app
-                .world()
-                .get::<Camera>(camera_id)
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_some()
+            );
+
+            let window_id = app
+                .world_mut()
+                .query_filtered::<Entity, With<PrimaryWindow>>()
+                .single(app.world())?;
+            app.world_mut()
+                .get_mut::<Window>(window_id)
                 .unwrap()
-                .to_owned()
-                .viewport
-                .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(0, 10));
-            assert_eq!(viewport.physical_size, UVec2::new(640, 345));
+                .resolution = UVec2::new(0, 360).into();
+            app.update();
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_none()
+            );
+
+            // A minimized window can have a height of zero, which shouldn't leave the viewport from
+            // the last valid size around.
+            app.world_mut()
+                .get_mut::<Window>(window_id)
+                .unwrap()
+                .resolution = W360P.into();
+            app.update();
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_some()
+            );
+            app.world_mut()
+                .get_mut::<Window>(window_id)
+                .unwrap()
+                .resolution = UVec2::new(640, 0).into();
+            app.update();
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_none()
+            );
+
+            // The viewport is cleared for a degenerate render target even if the plugin is set to
+            // leave viewports alone when nothing is boxed, since it would be wrong for the new size.
+            app.world_mut()
+                .resource_mut::<CameraBoxingSettings>()
+                .on_no_box = NoBoxBehavior::LeaveViewport;
+            app.world_mut()
+                .get_mut::<Window>(window_id)
+                .unwrap()
+                .resolution = W360P.into();
+            app.update();
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_some()
+            );
+            app.world_mut()
+                .get_mut::<Window>(window_id)
+                .unwrap()
+                .resolution = UVec2::new(0, 360).into();
+            app.update();
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_none()
+            );
 
             let (mut app, camera_id) = setup_app(
-                CameraBox::LetterBox {
-                    top: 5,
-                    bottom: 10,
-                    strict_letterboxing: true,
+                CameraBox::ResolutionIntegerScale {
+                    resolution: UVec2::new(640, 480),
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
                 },
-                W360P.into(),
+                W720P.into(),
             );
             app.update();
-            let viewport = app
-                .world()
-                .get::<Camera>(camera_id)
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_some()
+            );
+
+            let window_id = app
+                .world_mut()
+                .query_filtered::<Entity, With<PrimaryWindow>>()
+                .single(app.world())?;
+            app.world_mut()
+                .get_mut::<Window>(window_id)
                 .unwrap()
-                .to_owned()
-                .viewport
-                .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(0, 5));
-            assert_eq!(viewport.physical_size, UVec2::new(640, 345));
+                .resolution = UVec2::new(0, 360).into();
+            app.update();
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_none()
+            );
 
+            Ok(())
+        }
+
+        #[test]
+        fn test_strict_aspect_ratio() -> Result<()> {
             let (mut app, camera_id) = setup_app(
-                CameraBox::LetterBox {
-                    top: 360,
-                    bottom: 0,
-                    strict_letterboxing: true,
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::try_new(640., 480.)?,
+                    position: None,
+                    strict: true,
                 },
-                W360P.into(),
+                W720P.into(),
             );
             app.update();
             let viewport = app
@@ -1538,14 +9114,21 @@ mod tests {
                 .to_owned()
                 .viewport;
             assert!(viewport.is_none());
+            let errors = app.world().resource::<Messages<BoxingError>>();
+            let mut errors_reader = errors.get_cursor();
+            let error = errors_reader.read(errors).next();
+            assert!(matches!(
+                error,
+                Some(BoxingError::AspectMismatch { camera, .. }) if *camera == camera_id
+            ));
 
             let (mut app, camera_id) = setup_app(
-                CameraBox::LetterBox {
-                    top: 2,
-                    bottom: 2,
-                    strict_letterboxing: false,
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::try_from(W360P.as_vec2())?,
+                    position: None,
+                    strict: true,
                 },
-                W360P.into(),
+                W720P.into(),
             );
             app.update();
             let viewport = app
@@ -1553,16 +9136,25 @@ mod tests {
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport
-                .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(0, 2));
-            assert_eq!(viewport.physical_size, UVec2::new(640, 356));
+                .viewport;
+            assert!(viewport.is_none());
+            let errors = app.world().resource::<Messages<BoxingError>>();
+            let mut errors_reader = errors.get_cursor();
+            assert!(errors_reader.read(errors).next().is_none());
+
+            Ok(())
+        }
 
+        #[test]
+        fn test_basic_integer_scaling_imperfect() {
             let (mut app, camera_id) = setup_app(
-                CameraBox::LetterBox {
-                    top: 5,
-                    bottom: 0,
-                    strict_letterboxing: false,
+                CameraBox::ResolutionIntegerScale {
+                    resolution: W360P,
+                    allow_imperfect_downscaled_boxing: true,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
                 },
                 W360P.into(),
             );
@@ -1572,18 +9164,19 @@ mod tests {
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport
-                .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(0, 5));
-            assert_eq!(viewport.physical_size, UVec2::new(640, 355));
+                .viewport;
+            assert!(viewport.is_none());
 
             let (mut app, camera_id) = setup_app(
-                CameraBox::LetterBox {
-                    top: 0,
-                    bottom: 5,
-                    strict_letterboxing: false,
+                CameraBox::ResolutionIntegerScale {
+                    resolution: UVec2::new(640, 480),
+                    allow_imperfect_downscaled_boxing: true,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
                 },
-                W360P.into(),
+                W720P.into(),
             );
             app.update();
             let viewport = app
@@ -1593,16 +9186,19 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(0, 0));
-            assert_eq!(viewport.physical_size, UVec2::new(640, 355));
+            assert_eq!(viewport.physical_position, UVec2::new(320, 120));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 480));
 
             let (mut app, camera_id) = setup_app(
-                CameraBox::LetterBox {
-                    top: 10,
-                    bottom: 5,
-                    strict_letterboxing: false,
+                CameraBox::ResolutionIntegerScale {
+                    resolution: W360P,
+                    allow_imperfect_downscaled_boxing: true,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
                 },
-                W360P.into(),
+                W720P.into(),
             );
             app.update();
             let viewport = app
@@ -1610,18 +9206,19 @@ mod tests {
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport
-                .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(0, 10));
-            assert_eq!(viewport.physical_size, UVec2::new(640, 345));
+                .viewport;
+            assert!(viewport.is_none());
 
             let (mut app, camera_id) = setup_app(
-                CameraBox::LetterBox {
-                    top: 5,
-                    bottom: 10,
-                    strict_letterboxing: false,
+                CameraBox::ResolutionIntegerScale {
+                    resolution: W360P,
+                    allow_imperfect_downscaled_boxing: true,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
                 },
-                W360P.into(),
+                W180P.into(),
             );
             app.update();
             let viewport = app
@@ -1629,18 +9226,19 @@ mod tests {
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport
-                .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(0, 5));
-            assert_eq!(viewport.physical_size, UVec2::new(640, 345));
+                .viewport;
+            assert!(viewport.is_none());
 
-            let (mut app, camera_id) = setup_app(
-                CameraBox::LetterBox {
-                    top: 360,
-                    bottom: 0,
-                    strict_letterboxing: false,
+            let (mut app, camera_id) = setup_app(
+                CameraBox::ResolutionIntegerScale {
+                    resolution: W360P,
+                    allow_imperfect_downscaled_boxing: true,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
                 },
-                W360P.into(),
+                (W180P + 10).into(),
             );
             app.update();
             let viewport = app
@@ -1650,16 +9248,46 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(0, 180));
-            assert_eq!(viewport.physical_size, UVec2::new(640, 180));
+            assert_eq!(viewport.physical_position, UVec2::new(0, 2));
+            assert_eq!(viewport.physical_size, UVec2::new(330, 185));
         }
 
         #[test]
-        fn test_basic_resolution() {
+        fn test_used_imperfect_scaling() {
+            let integer_scale = CameraBox::ResolutionIntegerScale {
+                resolution: W720P,
+                allow_imperfect_downscaled_boxing: true,
+                independent_axis_scale: false,
+                fit: IntegerScaleFit::FitInside,
+                force_downscale: false,
+                min_content_fraction: None,
+            };
+            let used_imperfect_scaling = |window_size: UVec2| {
+                let (mut app, camera_id) = setup_app(integer_scale.clone(), window_size.into());
+                app.update();
+                app.world()
+                    .get::<ComputedBoxing>(camera_id)
+                    .unwrap()
+                    .used_imperfect_scaling
+            };
+
+            // There's no Integer Scale of 1280x720 that fits in 1000x500.
+            assert!(used_imperfect_scaling(UVec2::new(1000, 500)));
+            assert!(!used_imperfect_scaling(UVec2::new(1920, 1080)));
+            assert!(!used_imperfect_scaling(W360P));
+            assert!(!used_imperfect_scaling(W720P));
+        }
+
+        #[test]
+        fn test_basic_integer_scaling_perfect() {
             let (mut app, camera_id) = setup_app(
-                CameraBox::StaticResolution {
-                    resolution: W360P.into(),
-                    position: None,
+                CameraBox::ResolutionIntegerScale {
+                    resolution: W360P,
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
                 },
                 W360P.into(),
             );
@@ -1673,11 +9301,15 @@ mod tests {
             assert!(viewport.is_none());
 
             let (mut app, camera_id) = setup_app(
-                CameraBox::StaticResolution {
-                    resolution: W360P.into(),
-                    position: Some((1, 0).into()),
+                CameraBox::ResolutionIntegerScale {
+                    resolution: UVec2::new(640, 480),
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
                 },
-                W360P.into(),
+                W720P.into(),
             );
             app.update();
             let viewport = app
@@ -1685,13 +9317,19 @@ mod tests {
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport;
-            assert!(viewport.is_none());
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(320, 120));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 480));
 
             let (mut app, camera_id) = setup_app(
-                CameraBox::StaticResolution {
-                    resolution: W360P.into(),
-                    position: None,
+                CameraBox::ResolutionIntegerScale {
+                    resolution: W360P,
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
                 },
                 W720P.into(),
             );
@@ -1701,19 +9339,41 @@ mod tests {
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport
-                .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(320, 180));
-            assert_eq!(viewport.physical_size, W360P);
+                .viewport;
+            assert!(viewport.is_none());
 
             let (mut app, camera_id) = setup_app(
-                CameraBox::StaticResolution {
-                    resolution: W360P.into(),
-                    position: None,
+                CameraBox::ResolutionIntegerScale {
+                    resolution: W360P,
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
                 },
                 W180P.into(),
             );
             app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport;
+            assert!(viewport.is_none());
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::ResolutionIntegerScale {
+                    resolution: W360P,
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
+                },
+                (W180P + 10).into(),
+            );
+            app.update();
             let viewport = app
                 .world()
                 .get::<Camera>(camera_id)
@@ -1721,19 +9381,19 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(0, 0));
-            assert_eq!(viewport.physical_size, W180P);
+            assert_eq!(viewport.physical_position, UVec2::new(5, 5));
+            assert_eq!(viewport.physical_size, UVec2::new(320, 180));
         }
 
         #[test]
-        fn test_basic_aspect_ratio() -> Result<()> {
-            let desired_aspect_ratio = AspectRatio::try_from(W720P.as_vec2())?;
+        fn test_fixed_tiles() {
             let (mut app, camera_id) = setup_app(
-                CameraBox::StaticAspectRatio {
-                    aspect_ratio: desired_aspect_ratio,
-                    position: None,
+                CameraBox::FixedTiles {
+                    tile_size: UVec2::splat(16),
+                    cols: 20,
+                    rows: 15,
                 },
-                W360P.into(),
+                W720P.into(),
             );
             app.update();
             let viewport = app
@@ -1741,14 +9401,16 @@ mod tests {
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport;
-            assert!(viewport.is_none());
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(160, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(960, 720));
 
-            let desired_aspect_ratio = AspectRatio::try_new(640., 480.)?;
             let (mut app, camera_id) = setup_app(
-                CameraBox::StaticAspectRatio {
-                    aspect_ratio: desired_aspect_ratio,
-                    position: None,
+                CameraBox::FixedTiles {
+                    tile_size: UVec2::splat(16),
+                    cols: 40,
+                    rows: 22,
                 },
                 W720P.into(),
             );
@@ -1760,16 +9422,16 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(160, 0));
-            assert_eq!(viewport.physical_size, UVec2::new(960, 720));
+            assert_eq!(viewport.physical_position, UVec2::new(0, 8));
+            assert_eq!(viewport.physical_size, UVec2::new(1280, 704));
 
-            let desired_aspect_ratio = AspectRatio::try_from(W720P.as_vec2())?;
             let (mut app, camera_id) = setup_app(
-                CameraBox::StaticAspectRatio {
-                    aspect_ratio: desired_aspect_ratio,
-                    position: Some((1, 0).into()),
+                CameraBox::FixedTiles {
+                    tile_size: UVec2::splat(16),
+                    cols: 40,
+                    rows: 22,
                 },
-                W360P.into(),
+                UVec2::new(640, 352).into(),
             );
             app.update();
             let viewport = app
@@ -1779,18 +9441,224 @@ mod tests {
                 .to_owned()
                 .viewport;
             assert!(viewport.is_none());
+        }
+
+        #[test]
+        fn test_box_per_monitor() {
+            use bevy_ecs::system::SystemState;
+
+            let monitor = |size: UVec2| Monitor {
+                name: None,
+                physical_width: size.x,
+                physical_height: size.y,
+                physical_position: IVec2::ZERO,
+                refresh_rate_millihertz: None,
+                scale_factor: 1.,
+                video_modes: Vec::new(),
+            };
+            let mut world = World::new();
+            let exact = world.spawn(monitor(W720P)).id();
+            let wide = world.spawn(monitor(UVec2::new(2560, 1080))).id();
+            let tall = world.spawn(monitor(UVec2::new(1280, 1024))).id();
+
+            let mut monitors = SystemState::<Query<(Entity, &Monitor)>>::new(&mut world);
+            let boxes = box_per_monitor(
+                &CameraBox::static_aspect_ratio(AspectRatio::SIXTEEN_NINE),
+                &monitors.get(&world),
+            );
+
+            let viewport = |entity| {
+                boxes
+                    .iter()
+                    .find(|(monitor, _)| *monitor == entity)
+                    .unwrap()
+                    .1
+                    .clone()
+            };
+            assert!(viewport(exact).is_none());
+            let wide = viewport(wide).unwrap();
+            assert_eq!(wide.physical_position, UVec2::new(320, 0));
+            assert_eq!(wide.physical_size, UVec2::new(1920, 1080));
+            let tall = viewport(tall).unwrap();
+            assert_eq!(tall.physical_position, UVec2::new(0, 152));
+            assert_eq!(tall.physical_size, W720P);
+        }
+
+        #[test]
+        fn test_boxed_viewport() {
+            use bevy_ecs::system::SystemState;
+
+            let (mut app, camera_id) = setup_app(CameraBox::static_resolution(W360P), W720P.into());
+            let unboxed_id = app.world_mut().spawn(Camera::default()).id();
+            app.update();
+
+            let mut cameras = SystemState::<Query<&Camera>>::new(app.world_mut());
+            let cameras = cameras.get(app.world());
+            assert_eq!(
+                boxed_viewport(camera_id, &cameras),
+                Some(URect::new(320, 180, 960, 540))
+            );
+            assert_eq!(boxed_viewport(unboxed_id, &cameras), None);
+        }
+
+        #[test]
+        fn test_window_to_viewport() {
+            let (mut app, camera_id) = setup_app(CameraBox::static_resolution(W360P), W720P.into());
+            app.update();
+            let camera = app.world().get::<Camera>(camera_id).unwrap();
+
+            assert_eq!(
+                window_to_viewport(camera, Vec2::new(320., 180.)),
+                Some(Vec2::ZERO)
+            );
+            assert_eq!(
+                window_to_viewport(camera, Vec2::new(640., 360.)),
+                Some(Vec2::new(320., 180.))
+            );
+            assert_eq!(
+                window_to_viewport(camera, Vec2::new(959.5, 539.5)),
+                Some(Vec2::new(639.5, 359.5))
+            );
+
+            // These are all on the bars.
+            assert_eq!(window_to_viewport(camera, Vec2::new(100., 360.)), None);
+            assert_eq!(window_to_viewport(camera, Vec2::new(640., 600.)), None);
+            assert_eq!(window_to_viewport(camera, Vec2::new(960., 540.)), None);
+
+            let unboxed = Camera::default();
+            assert_eq!(
+                window_to_viewport(&unboxed, Vec2::new(100., 360.)),
+                Some(Vec2::new(100., 360.))
+            );
+        }
+
+        #[test]
+        fn test_missing_window_clears_viewport() {
+            let (mut app, camera_id) = setup_app(CameraBox::static_resolution(W360P), W720P.into());
+            app.update();
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_some()
+            );
+
+            let missing_window = app.world_mut().spawn_empty().id();
+            app.world_mut().despawn(missing_window);
+            app.world_mut()
+                .entity_mut(camera_id)
+                .insert(RenderTarget::Window(WindowRef::Entity(missing_window)));
+            app.update();
+            assert!(
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .viewport
+                    .is_none()
+            );
+        }
+
+        #[test]
+        fn test_cameras_boxed_in_order() {
+            let (mut app, last_id) = setup_app(CameraBox::static_resolution(W360P), W720P.into());
+            app.world_mut().get_mut::<Camera>(last_id).unwrap().order = 2;
+            let mut spawn_camera = |order| {
+                app.world_mut()
+                    .spawn((
+                        Camera {
+                            order,
+                            ..Camera::default()
+                        },
+                        RenderTarget::Window(WindowRef::Primary),
+                        CameraBox::static_resolution(W180P),
+                    ))
+                    .id()
+            };
+            let middle_id = spawn_camera(1);
+            let first_id = spawn_camera(-1);
+            app.update();
+
+            let messages = app.world().resource::<Messages<CameraBoxed>>();
+            let boxed = messages
+                .get_cursor()
+                .read(messages)
+                .map(|boxed| boxed.camera)
+                .collect::<Vec<_>>();
+            assert_eq!(boxed, vec![first_id, middle_id, last_id]);
+        }
+
+        #[test]
+        fn test_camera_box_group() -> Result<()> {
+            use bevy_ecs::system::SystemState;
+
+            let (mut app, leader_id) = setup_app(CameraBox::static_resolution(W360P), W720P.into());
+            let follower_id = app
+                .world_mut()
+                .spawn((
+                    Camera {
+                        order: 1,
+                        ..Camera::default()
+                    },
+                    RenderTarget::Window(WindowRef::Primary),
+                    CameraBox::static_aspect_ratio(AspectRatio::try_new(4., 3.)?),
+                    CameraBoxGroup(0),
+                ))
+                .id();
+            app.world_mut()
+                .entity_mut(leader_id)
+                .insert(CameraBoxGroup(0));
+            app.update();
+
+            let viewport = |app: &App, camera_id| {
+                app.world()
+                    .get::<Camera>(camera_id)
+                    .unwrap()
+                    .to_owned()
+                    .viewport
+                    .unwrap()
+            };
+            let leader = viewport(&app, leader_id);
+            let follower = viewport(&app, follower_id);
+            assert_eq!(leader.physical_position, UVec2::new(320, 180));
+            assert_eq!(leader.physical_size, W360P);
+            assert_eq!(follower.physical_position, leader.physical_position);
+            assert_eq!(follower.physical_size, leader.physical_size);
+
+            // Boxing only the follower still gives it the leader's boxing.
+            app.world_mut()
+                .entity_mut(follower_id)
+                .get_mut::<Camera>()
+                .unwrap()
+                .viewport = None;
+            let mut boxing = SystemState::<ViewportBoxing>::new(app.world_mut());
+            boxing.get_mut(app.world_mut()).adjust(Some(&[follower_id]));
+            boxing.apply(app.world_mut());
+            let follower = viewport(&app, follower_id);
+            assert_eq!(follower.physical_position, leader.physical_position);
+            assert_eq!(follower.physical_size, leader.physical_size);
+
+            // Without the group, the follower is boxed on its own again.
+            app.world_mut()
+                .entity_mut(follower_id)
+                .remove::<CameraBoxGroup>();
+            app.update();
+            let follower = viewport(&app, follower_id);
+            assert_eq!(follower.physical_position, UVec2::new(160, 0));
+            assert_eq!(follower.physical_size, UVec2::new(960, 720));
 
             Ok(())
         }
 
         #[test]
-        fn test_basic_integer_scaling_imperfect() {
+        fn test_aspect_ratio_with_margin() {
+            let target = UVec2::new(1920, 1080);
             let (mut app, camera_id) = setup_app(
-                CameraBox::ResolutionIntegerScale {
-                    resolution: W360P.as_vec2().into(),
-                    allow_imperfect_downscaled_boxing: true,
+                CameraBox::AspectRatioWithMargin {
+                    aspect_ratio: AspectRatio::SIXTEEN_NINE,
+                    margin: UVec4::splat(32),
                 },
-                W360P.into(),
+                target.into(),
             );
             app.update();
             let viewport = app
@@ -1798,15 +9666,19 @@ mod tests {
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport;
-            assert!(viewport.is_none());
+                .viewport
+                .unwrap();
+            // The 1856x1016 area left by the margins is too wide, so the output is pillarboxed
+            // within it.
+            assert_eq!(viewport.physical_position, UVec2::new(57, 32));
+            assert_eq!(viewport.physical_size, UVec2::new(1806, 1016));
 
             let (mut app, camera_id) = setup_app(
-                CameraBox::ResolutionIntegerScale {
-                    resolution: (640., 480.).into(),
-                    allow_imperfect_downscaled_boxing: true,
+                CameraBox::AspectRatioWithMargin {
+                    aspect_ratio: AspectRatio::SIXTEEN_NINE,
+                    margin: UVec4::new(32, 64, 32, 64),
                 },
-                W720P.into(),
+                target.into(),
             );
             app.update();
             let viewport = app
@@ -1816,15 +9688,17 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(320, 120));
-            assert_eq!(viewport.physical_size, UVec2::new(640, 480));
+            // The 1792x1016 area left by the margins is too tall, so the output is letterboxed
+            // within it.
+            assert_eq!(viewport.physical_position, UVec2::new(64, 36));
+            assert_eq!(viewport.physical_size, UVec2::new(1792, 1008));
 
             let (mut app, camera_id) = setup_app(
-                CameraBox::ResolutionIntegerScale {
-                    resolution: W360P.as_vec2(),
-                    allow_imperfect_downscaled_boxing: true,
+                CameraBox::AspectRatioWithMargin {
+                    aspect_ratio: AspectRatio::SIXTEEN_NINE,
+                    margin: UVec4::splat(960),
                 },
-                W720P.into(),
+                target.into(),
             );
             app.update();
             let viewport = app
@@ -1834,13 +9708,14 @@ mod tests {
                 .to_owned()
                 .viewport;
             assert!(viewport.is_none());
+        }
 
+        #[test]
+        fn test_integer_aspect() {
+            let base = UVec2::new(384, 216);
             let (mut app, camera_id) = setup_app(
-                CameraBox::ResolutionIntegerScale {
-                    resolution: W360P.as_vec2().into(),
-                    allow_imperfect_downscaled_boxing: true,
-                },
-                W180P.into(),
+                CameraBox::IntegerAspect { base },
+                UVec2::new(1920, 1080).into(),
             );
             app.update();
             let viewport = app
@@ -1852,11 +9727,8 @@ mod tests {
             assert!(viewport.is_none());
 
             let (mut app, camera_id) = setup_app(
-                CameraBox::ResolutionIntegerScale {
-                    resolution: W360P.as_vec2().into(),
-                    allow_imperfect_downscaled_boxing: true,
-                },
-                (W180P + 10).into(),
+                CameraBox::IntegerAspect { base },
+                UVec2::new(1700, 1000).into(),
             );
             app.update();
             let viewport = app
@@ -1866,35 +9738,175 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(0, 2));
-            assert_eq!(viewport.physical_size, UVec2::new(330, 185));
+            assert_eq!(viewport.physical_position, UVec2::new(82, 68));
+            assert_eq!(viewport.physical_size, base * 4);
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::IntegerAspect { base },
+                UVec2::new(300, 300).into(),
+            );
+            app.update();
+            let viewport = app
+                .world()
+                .get::<Camera>(camera_id)
+                .unwrap()
+                .to_owned()
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 65));
+            assert_eq!(viewport.physical_size, UVec2::new(300, 169));
+        }
+
+        #[test]
+        fn test_debug_snapshot() {
+            let (mut app, letterboxed_id) = setup_app(
+                CameraBox::LetterBox {
+                    top: 10,
+                    bottom: 10,
+                    strict_letterboxing: true,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                },
+                W360P.into(),
+            );
+            let unboxed_id = app
+                .world_mut()
+                .spawn((
+                    Camera::default(),
+                    RenderTarget::Window(WindowRef::Primary),
+                    CameraBox::StaticResolution {
+                        resolution: W360P,
+                        position: None,
+                        scale: ResolutionScaleMode::Exact,
+                    },
+                ))
+                .id();
+            app.init_resource::<BoxingDebugSnapshot>();
+            app.add_systems(First, update_debug_snapshot.after(adjust_viewport));
+            app.update();
+
+            let snapshot = app.world().resource::<BoxingDebugSnapshot>();
+            assert_eq!(snapshot.0.len(), 2);
+
+            let letterboxed = snapshot
+                .0
+                .iter()
+                .find(|entry| entry.camera == letterboxed_id)
+                .unwrap();
+            assert_eq!(
+                letterboxed.camera_box,
+                CameraBox::LetterBox {
+                    top: 10,
+                    bottom: 10,
+                    strict_letterboxing: true,
+                    preserve_bar_ratio: false,
+                    logical_bars: false,
+                }
+            );
+            assert_eq!(letterboxed.target_size, Some(W360P));
+            let viewport = letterboxed.viewport.as_ref().unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(0, 10));
+            assert_eq!(viewport.physical_size, UVec2::new(640, 340));
+
+            let unboxed = snapshot
+                .0
+                .iter()
+                .find(|entry| entry.camera == unboxed_id)
+                .unwrap();
+            assert_eq!(
+                unboxed.camera_box,
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
+                }
+            );
+            assert_eq!(unboxed.target_size, Some(W360P));
+            assert!(unboxed.viewport.is_none());
+        }
+
+        #[test]
+        fn test_computed_boxing_remainder() {
+            let (mut app, camera_id) = setup_app(
+                CameraBox::ResolutionIntegerScale {
+                    resolution: W360P,
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
+                },
+                UVec2::new(1000, 600).into(),
+            );
+            app.update();
+            let computed = app.world().get::<ComputedBoxing>(camera_id).unwrap();
+            assert_eq!(computed.target_size, UVec2::new(1000, 600));
+            assert_eq!(computed.output_resolution, Vec2::new(640., 360.));
+            assert_eq!(computed.boxing_offset, Vec2::new(180., 120.));
+            assert_eq!(computed.remainder, Vec2::new(360., 240.));
+
+            let (mut app, camera_id) = setup_app(
+                CameraBox::ResolutionIntegerScale {
+                    resolution: W360P,
+                    allow_imperfect_downscaled_boxing: false,
+                    independent_axis_scale: false,
+                    fit: IntegerScaleFit::FitInside,
+                    force_downscale: false,
+                    min_content_fraction: None,
+                },
+                W720P.into(),
+            );
+            app.update();
+            let computed = app.world().get::<ComputedBoxing>(camera_id).unwrap();
+            assert_eq!(computed.output_resolution, W720P.as_vec2());
+            assert_eq!(computed.remainder, Vec2::ZERO);
         }
 
         #[test]
-        fn test_basic_integer_scaling_perfect() {
+        fn test_sub_camera_view() -> Result<()> {
             let (mut app, camera_id) = setup_app(
-                CameraBox::ResolutionIntegerScale {
-                    resolution: W360P.as_vec2().into(),
-                    allow_imperfect_downscaled_boxing: false,
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::try_new(640., 480.)?,
+                    position: None,
+                    strict: false,
                 },
-                W360P.into(),
+                W720P.into(),
             );
+            app.world_mut()
+                .get_mut::<Camera>(camera_id)
+                .unwrap()
+                .sub_camera_view = Some(SubCameraView {
+                full_size: W720P,
+                offset: Vec2::ZERO,
+                size: W360P,
+            });
             app.update();
             let viewport = app
                 .world()
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport;
-            assert!(viewport.is_none());
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(80, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(480, 360));
 
             let (mut app, camera_id) = setup_app(
-                CameraBox::ResolutionIntegerScale {
-                    resolution: (640., 480.).into(),
-                    allow_imperfect_downscaled_boxing: false,
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::try_from(W360P.as_vec2())?,
+                    position: None,
+                    strict: false,
                 },
                 W720P.into(),
             );
+            app.world_mut()
+                .get_mut::<Camera>(camera_id)
+                .unwrap()
+                .sub_camera_view = Some(SubCameraView {
+                full_size: W720P,
+                offset: Vec2::ZERO,
+                size: W360P,
+            });
             app.update();
             let viewport = app
                 .world()
@@ -1903,58 +9915,187 @@ mod tests {
                 .to_owned()
                 .viewport
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(320, 120));
-            assert_eq!(viewport.physical_size, UVec2::new(640, 480));
+            assert_eq!(viewport.physical_position, UVec2::ZERO);
+            assert_eq!(viewport.physical_size, W360P);
+
+            Ok(())
+        }
 
+        #[test]
+        fn test_sub_camera_view_scaled_to_target() {
             let (mut app, camera_id) = setup_app(
-                CameraBox::ResolutionIntegerScale {
-                    resolution: W360P.as_vec2(),
-                    allow_imperfect_downscaled_boxing: false,
-                },
+                CameraBox::static_resolution(UVec2::new(320, 180)),
                 W720P.into(),
             );
+            // The sub view covers the top right quarter of a full view twice the size of the
+            // window, so it covers the window from (640, 0) to (1280, 360).
+            app.world_mut()
+                .get_mut::<Camera>(camera_id)
+                .unwrap()
+                .sub_camera_view = Some(SubCameraView {
+                full_size: W720P * 2,
+                offset: Vec2::new(1280., 0.),
+                size: W720P,
+            });
             app.update();
             let viewport = app
                 .world()
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport;
-            assert!(viewport.is_none());
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(800, 90));
+            assert_eq!(viewport.physical_size, UVec2::new(320, 180));
+
+            let computed = app.world().get::<ComputedBoxing>(camera_id).unwrap();
+            assert_eq!(computed.target_size, W720P);
+            assert_eq!(computed.boxing_offset, Vec2::new(800., 90.));
+            assert_eq!(computed.remainder, Vec2::new(320., 180.));
+        }
 
+        #[test]
+        fn test_sub_camera_view_with_viewport() -> Result<()> {
             let (mut app, camera_id) = setup_app(
-                CameraBox::ResolutionIntegerScale {
-                    resolution: W360P.as_vec2().into(),
-                    allow_imperfect_downscaled_boxing: false,
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::try_new(640., 480.)?,
+                    position: None,
+                    strict: false,
                 },
-                W180P.into(),
+                W720P.into(),
             );
+            let mut camera = app.world_mut().get_mut::<Camera>(camera_id).unwrap();
+            camera.sub_camera_view = Some(SubCameraView {
+                full_size: W720P,
+                offset: Vec2::ZERO,
+                size: W360P,
+            });
+            camera.viewport = Some(Viewport {
+                physical_position: UVec2::new(600, 300),
+                physical_size: UVec2::new(480, 360),
+                ..Viewport::default()
+            });
             app.update();
             let viewport = app
                 .world()
                 .get::<Camera>(camera_id)
                 .unwrap()
                 .to_owned()
-                .viewport;
-            assert!(viewport.is_none());
+                .viewport
+                .unwrap();
+            assert_eq!(viewport.physical_position, UVec2::new(80, 0));
+            assert_eq!(viewport.physical_size, UVec2::new(480, 360));
+
+            Ok(())
+        }
+
+        /// A square output placed so far to the right that it runs past the edge of the window.
+        fn setup_out_of_bounds_app(assert_viewports: bool) -> App {
+            let (mut app, _) = setup_app(
+                CameraBox::StaticAspectRatio {
+                    aspect_ratio: AspectRatio::ratio_1_1(),
+                    position: Some(UVec2::new(1000, 0)),
+                    strict: false,
+                },
+                W720P.into(),
+            );
+            app.world_mut()
+                .resource_mut::<CameraBoxingSettings>()
+                .assert_viewports = assert_viewports;
+            app
+        }
+
+        #[test]
+        #[cfg(debug_assertions)]
+        #[should_panic(expected = "doesn't fit within render target")]
+        fn test_assert_viewports() {
+            setup_out_of_bounds_app(true).update();
+        }
+
+        #[test]
+        fn test_assert_viewports_disabled() {
+            setup_out_of_bounds_app(false).update();
+        }
+
+        #[test]
+        fn test_oversized_config_stays_within_target() {
+            for camera_box in [
+                CameraBox::letterbox(u32::MAX, 1),
+                CameraBox::pillarbox(1, u32::MAX),
+                CameraBox::windowbox(u32::MAX, u32::MAX, u32::MAX, u32::MAX),
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: Some(UVec2::splat(u32::MAX)),
+                    scale: ResolutionScaleMode::Exact,
+                },
+            ] {
+                let (mut app, camera_id) = setup_app(camera_box.clone(), W720P.into());
+                app.world_mut()
+                    .resource_mut::<CameraBoxingSettings>()
+                    .assert_viewports = true;
+                app.update();
+                if let Some(viewport) = &app.world().get::<Camera>(camera_id).unwrap().viewport {
+                    assert!(
+                        (viewport.physical_position + viewport.physical_size)
+                            .cmple(W720P)
+                            .all(),
+                        "{camera_box:?} placed a viewport outside of the render target",
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_log_boxing_changes_only_on_change() {
+            #[derive(Resource, Default)]
+            struct ChangeCount(u32);
+
+            fn count_changes(
+                mut count: ResMut<ChangeCount>,
+                boxes: Query<(), Changed<ComputedBoxing>>,
+            ) {
+                count.0 += boxes.iter().count() as u32;
+            }
 
             let (mut app, camera_id) = setup_app(
-                CameraBox::ResolutionIntegerScale {
-                    resolution: W360P.as_vec2().into(),
-                    allow_imperfect_downscaled_boxing: false,
+                CameraBox::StaticResolution {
+                    resolution: W360P,
+                    position: None,
+                    scale: ResolutionScaleMode::Exact,
                 },
-                (W180P + 10).into(),
+                W360P.into(),
+            );
+            app.init_resource::<ChangeCount>();
+            app.add_systems(
+                First,
+                (log_boxing_changes, count_changes)
+                    .chain()
+                    .after(adjust_viewport),
             );
             app.update();
-            let viewport = app
-                .world()
-                .get::<Camera>(camera_id)
-                .unwrap()
-                .to_owned()
-                .viewport
+            app.update();
+            app.update();
+            assert_eq!(app.world().resource::<ChangeCount>().0, 1);
+
+            let window_id = app
+                .world_mut()
+                .query_filtered::<Entity, With<PrimaryWindow>>()
+                .single(app.world())
                 .unwrap();
-            assert_eq!(viewport.physical_position, UVec2::new(5, 5));
-            assert_eq!(viewport.physical_size, UVec2::new(320, 180));
+            app.world_mut()
+                .get_mut::<Window>(window_id)
+                .unwrap()
+                .resolution = W720P.into();
+            app.update();
+            app.update();
+            assert_eq!(app.world().resource::<ChangeCount>().0, 2);
+            assert_eq!(
+                app.world()
+                    .get::<ComputedBoxing>(camera_id)
+                    .unwrap()
+                    .to_string(),
+                "Windowboxed 1280x720 -> content 640x360 bars(320,320,180,180)"
+            );
         }
 
         #[test]
@@ -1963,6 +10104,10 @@ mod tests {
 
             app.init_resource::<ManualTextureViews>();
             app.init_resource::<Assets<Image>>();
+            app.init_resource::<CameraBoxingSettings>();
+            app.add_message::<BoxingError>();
+            app.add_message::<BoxingToggled>();
+            app.add_message::<CameraBoxed>();
             app.world_mut().spawn((
                 Window {
                     resolution: W360P.into(),
@@ -1982,6 +10127,7 @@ mod tests {
                     CameraBox::StaticResolution {
                         resolution: W360P,
                         position: None,
+                        scale: ResolutionScaleMode::Exact,
                     },
                 ))
                 .id();
@@ -1996,6 +10142,8 @@ mod tests {
                 top: 10,
                 bottom: 10,
                 strict_letterboxing: true,
+                preserve_bar_ratio: false,
+                logical_bars: false,
             };
             app.update();
             let adjust_boxing_events = app.world().resource::<Messages<AdjustBoxing>>();
@@ -2005,12 +10153,82 @@ mod tests {
             assert!(boxing_adjust.is_some())
         }
 
+        /// Runs `change` on a boxed camera once it has settled, and returns if `AdjustBoxing` was
+        /// sent because of it.
+        fn config_change_detected(change: impl FnOnce(&mut EntityWorldMut)) -> bool {
+            let mut app = App::new();
+            app.add_message::<AdjustBoxing>();
+            app.add_systems(First, camerabox_changed);
+            let camera_id = app
+                .world_mut()
+                .spawn((
+                    Camera::default(),
+                    CameraBox::static_resolution(W360P),
+                    BoxAnchor::TopLeft,
+                    AnchoredOffset {
+                        anchor: BoxAnchor::TopLeft,
+                        offset: IVec2::ZERO,
+                    },
+                    CameraBoxEnabled(true),
+                ))
+                .id();
+            app.update();
+            app.update();
+
+            let mut reader = app
+                .world()
+                .resource::<Messages<AdjustBoxing>>()
+                .get_cursor_current();
+            change(&mut app.world_mut().entity_mut(camera_id));
+            app.update();
+            reader
+                .read(app.world().resource::<Messages<AdjustBoxing>>())
+                .next()
+                .is_some()
+        }
+
+        #[test]
+        fn test_config_changed_detection() {
+            assert!(!config_change_detected(|_| ()));
+            assert!(config_change_detected(|camera| {
+                camera.insert(CameraBox::letterbox(10, 10));
+            }));
+            assert!(config_change_detected(|camera| {
+                camera.insert(PendingTargetSize(Some(W720P)));
+            }));
+            assert!(config_change_detected(|camera| {
+                camera.insert(BoxAnchor::BottomRight);
+            }));
+            assert!(config_change_detected(|camera| {
+                camera.remove::<BoxAnchor>();
+            }));
+            assert!(config_change_detected(|camera| {
+                camera.insert(AnchoredOffset {
+                    anchor: BoxAnchor::Center,
+                    offset: IVec2::new(4, 4),
+                });
+            }));
+            assert!(config_change_detected(|camera| {
+                camera.remove::<AnchoredOffset>();
+            }));
+            assert!(config_change_detected(|camera| {
+                camera.insert(CameraBoxEnabled(false));
+            }));
+            assert!(config_change_detected(|camera| {
+                camera.remove::<CameraBoxEnabled>();
+            }));
+        }
+
         #[test]
         fn test_window_changed_detection() {
             let mut app = App::new();
 
             app.init_resource::<ManualTextureViews>();
             app.init_resource::<Assets<Image>>();
+            app.init_resource::<CameraBoxingSettings>();
+            app.add_message::<BoxingError>();
+            app.add_message::<BoxingToggled>();
+            app.add_message::<CameraBoxed>();
             let window_id = app
                 .world_mut()
                 .spawn((
@@ -2024,6 +10242,7 @@ mod tests {
             app.world_mut().spawn((CameraBox::StaticResolution {
                 resolution: W360P,
                 position: None,
+                scale: ResolutionScaleMode::Exact,
             },));
             app.add_systems(
                 First,
@@ -2041,12 +10260,148 @@ mod tests {
             assert!(boxing_adjust.is_some())
         }
 
+        #[test]
+        fn test_locked_window_changed_detection() {
+            let mut app = App::new();
+
+            app.add_message::<AdjustBoxing>();
+            let window_id = app
+                .world_mut()
+                .spawn((
+                    Window {
+                        resolution: W360P.into(),
+                        resizable: false,
+                        ..Window::default()
+                    },
+                    PrimaryWindow,
+                ))
+                .id();
+            app.world_mut().spawn((CameraBox::StaticResolution {
+                resolution: W360P,
+                position: None,
+                scale: ResolutionScaleMode::Exact,
+            },));
+            app.add_systems(
+                First,
+                windows_changed.run_if(any_with_component::<CameraBox>),
+            );
+            let mut adjust_boxing_reader = app
+                .world()
+                .resource::<Messages<AdjustBoxing>>()
+                .get_cursor();
+            let mut count_adjustments = |app: &App| {
+                let messages = app.world().resource::<Messages<AdjustBoxing>>();
+                adjust_boxing_reader.read(messages).count()
+            };
+
+            app.update();
+            assert_eq!(count_adjustments(&app), 1);
+
+            for frame in 0..5 {
+                let mut window = app.world_mut().get_mut::<Window>(window_id).unwrap();
+                window.title = format!("Frame {frame}");
+                app.update();
+            }
+            assert_eq!(count_adjustments(&app), 0);
+
+            let mut window = app.world_mut().get_mut::<Window>(window_id).unwrap();
+            window.resolution.set_scale_factor(2.);
+            app.update();
+            assert_eq!(count_adjustments(&app), 1);
+
+            // Going fullscreen doesn't change the size of the window, but can still unbox it.
+            let mut window = app.world_mut().get_mut::<Window>(window_id).unwrap();
+            window.mode =
+                WindowMode::Fullscreen(MonitorSelection::Primary, VideoModeSelection::Current);
+            app.update();
+            assert_eq!(count_adjustments(&app), 1);
+
+            let mut window = app.world_mut().get_mut::<Window>(window_id).unwrap();
+            window.mode = WindowMode::Windowed;
+            app.update();
+            assert_eq!(count_adjustments(&app), 1);
+
+            let mut window = app.world_mut().get_mut::<Window>(window_id).unwrap();
+            window.resolution.set_scale_factor_override(Some(1.));
+            app.update();
+            assert_eq!(count_adjustments(&app), 1);
+        }
+
+        #[test]
+        fn test_stability_threshold() {
+            let mut app = App::new();
+
+            app.add_message::<AdjustBoxing>();
+            app.insert_resource(CameraBoxingSettings {
+                stability_threshold: 2,
+                ..CameraBoxingSettings::default()
+            });
+            let window_id = app
+                .world_mut()
+                .spawn((
+                    Window {
+                        resolution: W720P.into(),
+                        ..Window::default()
+                    },
+                    PrimaryWindow,
+                ))
+                .id();
+            app.world_mut().spawn((CameraBox::StaticResolution {
+                resolution: W360P,
+                position: None,
+                scale: ResolutionScaleMode::Exact,
+            },));
+            app.add_systems(
+                First,
+                windows_changed.run_if(any_with_component::<CameraBox>),
+            );
+            let mut adjust_boxing_reader = app
+                .world()
+                .resource::<Messages<AdjustBoxing>>()
+                .get_cursor();
+            let mut count_adjustments = |app: &App| {
+                let messages = app.world().resource::<Messages<AdjustBoxing>>();
+                adjust_boxing_reader.read(messages).count()
+            };
+
+            app.update();
+            assert_eq!(count_adjustments(&app), 1);
+
+            for size in [W720P + UVec2::X, W720P, W720P + UVec2::Y] {
+                let mut window = app.world_mut().get_mut::<Window>(window_id).unwrap();
+                window.resolution.set_physical_resolution(size.x, size.y);
+                app.update();
+            }
+            assert_eq!(count_adjustments(&app), 0);
+
+            let mut window = app.world_mut().get_mut::<Window>(window_id).unwrap();
+            window
+                .resolution
+                .set_physical_resolution(W720P.x + 3, W720P.y);
+            app.update();
+            assert_eq!(count_adjustments(&app), 1);
+
+            // A change in mode is never jitter, even if the size barely changes with it.
+            let mut window = app.world_mut().get_mut::<Window>(window_id).unwrap();
+            window.mode =
+                WindowMode::Fullscreen(MonitorSelection::Primary, VideoModeSelection::Current);
+            window
+                .resolution
+                .set_physical_resolution(W720P.x + 2, W720P.y);
+            app.update();
+            assert_eq!(count_adjustments(&app), 1);
+        }
+
         #[test]
         fn test_image_changed_detection() {
             let mut app = App::new();
 
             app.init_resource::<ManualTextureViews>();
             app.init_resource::<Assets<Image>>();
+            app.init_resource::<CameraBoxingSettings>();
+            app.add_message::<BoxingError>();
+            app.add_message::<BoxingToggled>();
+            app.add_message::<CameraBoxed>();
             app.add_message::<AssetEvent<Image>>();
             app.add_message::<AdjustBoxing>();
             app.add_systems(
@@ -2058,56 +10413,58 @@ mod tests {
                     ),
                 ),
             );
+            let mut adjust_boxing_reader = app
+                .world()
+                .resource::<Messages<AdjustBoxing>>()
+                .get_cursor();
+            let mut count_adjustments = |app: &App| {
+                let messages = app.world().resource::<Messages<AdjustBoxing>>();
+                adjust_boxing_reader.read(messages).count()
+            };
             app.update();
 
             let mut images = app.world_mut().resource_mut::<Assets<Image>>();
             images.add(Image::default());
             app.update();
-            let adjust_boxing_events = app.world().resource::<Messages<AdjustBoxing>>();
-            let mut adjust_boxing_reader = adjust_boxing_events.get_cursor();
-            let boxing_adjust = adjust_boxing_reader.read(adjust_boxing_events).next();
-            assert!(boxing_adjust.is_none());
-
-            let event = AssetEvent::Modified {
-                id: AssetId::default(),
-            };
-            app.world_mut().write_message::<AssetEvent<Image>>(event);
-            app.update();
-            let adjust_boxing_events = app.world().resource::<Messages<AdjustBoxing>>();
-            let mut adjust_boxing_reader = adjust_boxing_events.get_cursor();
-            let boxing_adjust = adjust_boxing_reader.read(adjust_boxing_events).next();
-            assert!(boxing_adjust.is_none());
+            assert_eq!(count_adjustments(&app), 0);
 
-            app.world_mut().spawn(CameraBox::LetterBox {
-                top: 0,
-                bottom: 0,
-                strict_letterboxing: true,
-            });
+            let target = app
+                .world_mut()
+                .resource_mut::<Assets<Image>>()
+                .add(Image::default());
+            app.world_mut().spawn((
+                CameraBox::letterbox(0, 0),
+                RenderTarget::Image(target.clone().into()),
+            ));
             app.update();
+            assert_eq!(count_adjustments(&app), 1);
 
+            // Neither of these change an image a boxed camera renders to.
             let mut images = app.world_mut().resource_mut::<Assets<Image>>();
             images.add(Image::default());
             app.update();
-            let adjust_boxing_events = app.world().resource::<Messages<AdjustBoxing>>();
-            let mut adjust_boxing_reader = adjust_boxing_events.get_cursor();
-            let boxing_adjust = adjust_boxing_reader.read(adjust_boxing_events).next();
-            assert!(boxing_adjust.is_some());
-
             let event = AssetEvent::Modified {
                 id: AssetId::default(),
             };
             app.world_mut().write_message::<AssetEvent<Image>>(event);
             app.update();
-            let adjust_boxing_events = app.world().resource::<Messages<AdjustBoxing>>();
-            let mut adjust_boxing_reader = adjust_boxing_events.get_cursor();
-            let boxing_adjust = adjust_boxing_reader.read(adjust_boxing_events).next();
-            assert!(boxing_adjust.is_some());
+            assert_eq!(count_adjustments(&app), 0);
+
+            app.world_mut()
+                .resource_mut::<Assets<Image>>()
+                .get_mut(&target)
+                .unwrap()
+                .texture_descriptor
+                .size = Extent3d {
+                width: W360P.x,
+                height: W360P.y,
+                depth_or_array_layers: 1,
+            };
             app.update();
+            assert_eq!(count_adjustments(&app), 1);
 
-            let adjust_boxing_events = app.world().resource::<Messages<AdjustBoxing>>();
-            let mut adjust_boxing_reader = adjust_boxing_events.get_cursor();
-            let boxing_adjust = adjust_boxing_reader.read(adjust_boxing_events).next();
-            assert!(boxing_adjust.is_none());
+            app.update();
+            assert_eq!(count_adjustments(&app), 0);
         }
 
         #[test]
@@ -2116,6 +10473,10 @@ mod tests {
 
             app.init_resource::<ManualTextureViews>();
             app.init_resource::<Assets<Image>>();
+            app.init_resource::<CameraBoxingSettings>();
+            app.add_message::<BoxingError>();
+            app.add_message::<BoxingToggled>();
+            app.add_message::<CameraBoxed>();
             app.add_message::<AdjustBoxing>();
             app.update();
             app.add_systems(
@@ -2136,12 +10497,27 @@ mod tests {
             let boxing_adjust = adjust_boxing_reader.read(adjust_boxing_events).next();
             assert!(boxing_adjust.is_none());
 
+            // This camera doesn't render to a texture view, so changes to them don't affect it.
             app.world_mut().spawn(CameraBox::LetterBox {
                 top: 0,
                 bottom: 0,
                 strict_letterboxing: false,
+                preserve_bar_ratio: false,
+                logical_bars: false,
             });
 
+            let _ = app.world_mut().resource_mut::<ManualTextureViews>();
+            app.update();
+            let adjust_boxing_events = app.world().resource::<Messages<AdjustBoxing>>();
+            let mut adjust_boxing_reader = adjust_boxing_events.get_cursor();
+            let boxing_adjust = adjust_boxing_reader.read(adjust_boxing_events).next();
+            assert!(boxing_adjust.is_none());
+
+            app.world_mut().spawn((
+                CameraBox::letterbox(0, 0),
+                RenderTarget::TextureView(ManualTextureViewHandle(0)),
+            ));
+
             let _ = app.world_mut().resource_mut::<ManualTextureViews>();
             app.update();
             let adjust_boxing_events = app.world().resource::<Messages<AdjustBoxing>>();
@@ -2155,6 +10531,10 @@ mod tests {
 
             app.init_resource::<ManualTextureViews>();
             app.init_resource::<Assets<Image>>();
+            app.init_resource::<CameraBoxingSettings>();
+            app.add_message::<BoxingError>();
+            app.add_message::<BoxingToggled>();
+            app.add_message::<CameraBoxed>();
             app.world_mut().spawn((
                 Window {
                     resolution: W720P.into(),
@@ -2174,6 +10554,7 @@ mod tests {
                     CameraBox::StaticResolution {
                         resolution: W360P,
                         position: None,
+                        scale: ResolutionScaleMode::Exact,
                     },
                 ))
                 .id();
@@ -2183,6 +10564,7 @@ mod tests {
                     CameraBox::StaticResolution {
                         resolution: W180P,
                         position: None,
+                        scale: ResolutionScaleMode::Exact,
                     },
                     NestedWithin(camera_id),
                 ))
@@ -2203,6 +10585,7 @@ mod tests {
             *parent_box = CameraBox::StaticResolution {
                 resolution: W360P,
                 position: Some((10, 10).into()),
+                scale: ResolutionScaleMode::Exact,
             };
             app.update();
             let viewport = app
@@ -2219,6 +10602,7 @@ mod tests {
             *child_box = CameraBox::StaticResolution {
                 resolution: W180P,
                 position: Some((10, 10).into()),
+                scale: ResolutionScaleMode::Exact,
             };
             app.update();
             let viewport = app
@@ -2235,6 +10619,7 @@ mod tests {
             *parent_box = CameraBox::StaticResolution {
                 resolution: W360P,
                 position: None,
+                scale: ResolutionScaleMode::Exact,
             };
             app.update();
             let viewport = app
@@ -2254,6 +10639,10 @@ mod tests {
 
             app.init_resource::<ManualTextureViews>();
             app.init_resource::<Assets<Image>>();
+            app.init_resource::<CameraBoxingSettings>();
+            app.add_message::<BoxingError>();
+            app.add_message::<BoxingToggled>();
+            app.add_message::<CameraBoxed>();
             app.world_mut().spawn((
                 Window {
                     resolution: W360P.into(),
@@ -2273,6 +10662,7 @@ mod tests {
                     CameraBox::StaticResolution {
                         resolution: W180P,
                         position: None,
+                        scale: ResolutionScaleMode::Exact,
                     },
                 ))
                 .id();
@@ -2280,6 +10670,7 @@ mod tests {
                 CameraBox::StaticResolution {
                     resolution: W180P,
                     position: None,
+                    scale: ResolutionScaleMode::Exact,
                 },
                 NestedWithin(camera_id),
             ));
@@ -2302,6 +10693,10 @@ mod tests {
 
             app.init_resource::<ManualTextureViews>();
             app.init_resource::<Assets<Image>>();
+            app.init_resource::<CameraBoxingSettings>();
+            app.add_message::<BoxingError>();
+            app.add_message::<BoxingToggled>();
+            app.add_message::<CameraBoxed>();
             app.world_mut().spawn((
                 Window {
                     resolution: W360P.into(),
@@ -2321,6 +10716,7 @@ mod tests {
                     CameraBox::StaticResolution {
                         resolution: W360P,
                         position: None,
+                        scale: ResolutionScaleMode::Exact,
                     },
                 ))
                 .id();
@@ -2328,6 +10724,7 @@ mod tests {
                 CameraBox::StaticResolution {
                     resolution: W180P,
                     position: None,
+                    scale: ResolutionScaleMode::Exact,
                 },
                 NestedWithin(camera_id),
             ));
@@ -2350,6 +10747,10 @@ mod tests {
 
             app.init_resource::<ManualTextureViews>();
             app.init_resource::<Assets<Image>>();
+            app.init_resource::<CameraBoxingSettings>();
+            app.add_message::<BoxingError>();
+            app.add_message::<BoxingToggled>();
+            app.add_message::<CameraBoxed>();
             app.world_mut().spawn((
                 Window {
                     resolution: W360P.into(),
@@ -2369,6 +10770,7 @@ mod tests {
                     CameraBox::StaticResolution {
                         resolution: W360P,
                         position: None,
+                        scale: ResolutionScaleMode::Exact,
                     },
                 ))
                 .id();
@@ -2376,6 +10778,7 @@ mod tests {
                 CameraBox::StaticResolution {
                     resolution: W360P,
                     position: None,
+                    scale: ResolutionScaleMode::Exact,
                 },
                 NestedWithin(camera_id),
             ));